@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as TestAddress, Bytes as TestBytes},
+    testutils::{Address as TestAddress, Bytes as TestBytes, Ledger},
     Address, Env, Bytes, Vec, Symbol,
 };
 use crate::compression::{
@@ -11,7 +11,7 @@ use crate::compression::{
 };
 use crate::storage_optimization::{
     BloomFilter, DataSeparator, CompressedReportStorage, ScoreStorage,
-    DataTemperature, StorageTier
+    DataTemperature, StorageTier, DecayConfig, DecayModel
 };
 use crate::data_migration::{
     DataMigrationManager, MigrationConfig, MigrationStatus
@@ -290,6 +290,64 @@ fn test_score_storage() {
     assert_eq!(history.len(), 3);
 }
 
+#[test]
+fn test_score_decay_models() {
+    let mut config = DecayConfig {
+        model: DecayModel::Linear,
+        neutral_score: 500,
+        period_seconds: 1000,
+        grace_period_seconds: 0,
+        stale_after_seconds: 1000,
+    };
+    
+    assert_eq!(config.apply(900, 500), 700);
+    assert_eq!(config.apply(100, 500), 300);
+    assert_eq!(config.apply(900, 5000), 500);
+    
+    config.model = DecayModel::HalfLife;
+    assert_eq!(config.apply(900, 1000), 700);
+    assert_eq!(config.apply(900, 1500), 650);
+    assert_eq!(config.apply(900, 2000), 600);
+    assert_eq!(config.apply(100, 1000), 300);
+    
+    // Nothing decays inside the grace period
+    config.grace_period_seconds = 1000;
+    assert_eq!(config.apply(900, 1000), 900);
+    
+    config.period_seconds = 0;
+    assert!(config.validate().is_err());
+    
+    config.period_seconds = 1000;
+    config.neutral_score = 1001;
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_score_status_staleness() {
+    let env = Env::default();
+    let address = TestAddress::generate(&env);
+    
+    ScoreStorage::set_decay_config(&env, &DecayConfig {
+        model: DecayModel::Linear,
+        neutral_score: 500,
+        period_seconds: 1000,
+        grace_period_seconds: 0,
+        stale_after_seconds: 600,
+    }).unwrap();
+    
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    ScoreStorage::store_score(&env, &address, 800, 10_000).unwrap();
+    
+    env.ledger().with_mut(|li| li.timestamp = 10_500);
+    let status = ScoreStorage::get_score_status(&env, &address).unwrap();
+    assert_eq!(status.raw_score, 800);
+    assert_eq!(status.score, 650);
+    assert!(!status.is_stale);
+    
+    env.ledger().with_mut(|li| li.timestamp = 10_600);
+    assert!(ScoreStorage::get_score_status(&env, &address).unwrap().is_stale);
+}
+
 #[test]
 fn test_data_migration() {
     let env = Env::default();
@@ -0,0 +1,97 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::adapter::TokenPair;
+
+/// Number of individual trades retained per account; totals cover all trades
+pub const MAX_RECENT_TRADES: u32 = 200;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountTrade {
+    pub pair: TokenPair,
+    pub is_buy: bool,
+    pub amount_usd: i128,
+    pub slippage_bps: u32,
+    /// Seconds the position was held before this trade closed it (0 if opening)
+    pub holding_period_seconds: u64,
+    pub timestamp: u64,
+}
+
+impl AccountTrade {
+    pub fn is_valid(&self) -> bool {
+        self.amount_usd > 0 && self.slippage_bps <= 10000
+    }
+}
+
+/// Trading activity of a single account across every pair it trades, appended
+/// to by an authorized indexer. Totals are account-wide; `AccountTrade::pair`
+/// only records where each retained trade happened.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountTradingLedger {
+    pub account: Address,
+    pub recent_trades: Vec<AccountTrade>,
+    pub trade_count: u64,
+    pub total_volume_usd: i128,
+    pub total_slippage_bps: u64,
+    pub closed_positions: u64,
+    pub total_holding_seconds: u64,
+    pub first_trade_at: u64,
+    pub last_trade_at: u64,
+}
+
+impl AccountTradingLedger {
+    pub fn new(env: &Env, account: Address) -> Self {
+        Self {
+            account,
+            recent_trades: Vec::new(env),
+            trade_count: 0,
+            total_volume_usd: 0,
+            total_slippage_bps: 0,
+            closed_positions: 0,
+            total_holding_seconds: 0,
+            first_trade_at: 0,
+            last_trade_at: 0,
+        }
+    }
+
+    pub fn record(&mut self, trade: AccountTrade) {
+        if self.trade_count == 0 || trade.timestamp < self.first_trade_at {
+            self.first_trade_at = trade.timestamp;
+        }
+        if trade.timestamp > self.last_trade_at {
+            self.last_trade_at = trade.timestamp;
+        }
+
+        self.trade_count += 1;
+        self.total_volume_usd += trade.amount_usd;
+        self.total_slippage_bps += trade.slippage_bps as u64;
+        if trade.holding_period_seconds > 0 {
+            self.closed_positions += 1;
+            self.total_holding_seconds += trade.holding_period_seconds;
+        }
+
+        if self.recent_trades.len() >= MAX_RECENT_TRADES {
+            self.recent_trades.pop_front();
+        }
+        self.recent_trades.push_back(trade);
+    }
+
+    pub fn avg_slippage_bps(&self) -> u32 {
+        if self.trade_count == 0 {
+            return 0;
+        }
+        (self.total_slippage_bps / self.trade_count) as u32
+    }
+
+    pub fn avg_holding_period_seconds(&self) -> u64 {
+        if self.closed_positions == 0 {
+            return 0;
+        }
+        self.total_holding_seconds / self.closed_positions
+    }
+
+    pub fn active_period_seconds(&self) -> u64 {
+        self.last_trade_at.saturating_sub(self.first_trade_at)
+    }
+}
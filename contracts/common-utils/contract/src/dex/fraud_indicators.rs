@@ -1,6 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 
-use super::adapter::TokenPair;
+use super::adapter::{DexError, TokenPair};
+use super::trade_stream::TradeStream;
 use super::trading_data::{TradingData, TradingVolume};
 
 #[contracttype]
@@ -229,6 +230,66 @@ pub enum PatternType {
     MomentumReversal,
 }
 
+/// Order book and trade sequence activity for a pair over one observation
+/// window, derived from the pair's `TradeStream` and the indexer's latest
+/// `OrderBookSummary`. Aggregate `TradingData` carries no order or
+/// sequencing information, which the manipulation detectors need.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderFlowStats {
+    pub window_start: u64,
+    pub window_end: u64,
+    pub orders_placed: u32,
+    pub orders_cancelled: u32,
+    /// Placements, amendments and cancels in the busiest second of the window
+    pub peak_messages_per_sec: u32,
+    /// Most price levels a single trader held on one side at the same time
+    pub max_layered_levels: u32,
+    /// Trades placed just ahead of a larger trade in the same direction
+    pub shadowed_trades: u32,
+    /// Shortest gap between a shadowing trade and the trade it preceded
+    pub min_shadow_gap_ms: u64,
+    /// Signed price change from the window open to its extreme
+    pub run_up_bps: i32,
+    /// Signed price change from the extreme to the window close
+    pub reversal_bps: i32,
+    pub traded_volume: i128,
+    /// Volume that came back to its originating trader through other accounts
+    pub circular_volume: i128,
+    pub pool_liquidity: i128,
+    /// Liquidity that was both added and removed within the window
+    pub flash_liquidity: i128,
+}
+
+impl OrderFlowStats {
+    pub fn new(window_start: u64, window_end: u64) -> Self {
+        Self {
+            window_start,
+            window_end,
+            orders_placed: 0,
+            orders_cancelled: 0,
+            peak_messages_per_sec: 0,
+            max_layered_levels: 0,
+            shadowed_trades: 0,
+            min_shadow_gap_ms: 0,
+            run_up_bps: 0,
+            reversal_bps: 0,
+            traded_volume: 0,
+            circular_volume: 0,
+            pool_liquidity: 0,
+            flash_liquidity: 0,
+        }
+    }
+
+    pub fn cancel_rate(&self) -> u32 {
+        if self.orders_placed == 0 {
+            return 0;
+        }
+        (self.orders_cancelled.min(self.orders_placed) as u64 * 100 / self.orders_placed as u64)
+            as u32
+    }
+}
+
 pub struct PatternDetector<'a> {
     env: &'a Env,
     thresholds: DetectionThresholds,
@@ -243,6 +304,17 @@ pub struct DetectionThresholds {
     pub unusual_volume_multiplier: i128,
     pub price_deviation_bps: u32,
     pub min_confidence_for_alert: u32,
+    /// Price levels one trader must stack on a single side to count as layering
+    pub layering_min_levels: u32,
+    /// Order book messages per second from which quote stuffing is flagged
+    pub quote_stuffing_msgs_per_sec: u32,
+    /// Run-up from which a move followed by a reversal is a pump and dump
+    /// rather than momentum ignition (which starts at `price_deviation_bps`)
+    pub pump_price_change_bps: u32,
+    /// Share of traded volume that returned to its originator
+    pub circular_volume_pct: u32,
+    /// Liquidity added and removed within the window, relative to the pool
+    pub flash_liquidity_bps: u32,
 }
 
 impl Default for DetectionThresholds {
@@ -254,6 +326,11 @@ impl Default for DetectionThresholds {
             unusual_volume_multiplier: 500,
             price_deviation_bps: 500,
             min_confidence_for_alert: 60,
+            layering_min_levels: 4,
+            quote_stuffing_msgs_per_sec: 50,
+            pump_price_change_bps: 2000,
+            circular_volume_pct: 20,
+            flash_liquidity_bps: 2000,
         }
     }
 }
@@ -262,6 +339,41 @@ impl DetectionThresholds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Every field must fall in a range where its detector can still fire.
+    /// Ratios, multipliers and minimums must be non-zero, percentages at
+    /// most 100 and basis points at most 10000 (the pump threshold may go up
+    /// to a 10x move but must exceed `price_deviation_bps`).
+    pub fn validate(&self) -> Result<(), DexError> {
+        let in_range = |value: u32, min: u32, max: u32| value >= min && value <= max;
+
+        if !(1..=1_000_000).contains(&self.wash_trade_volume_ratio)
+            || !(1..=1_000).contains(&self.unusual_volume_multiplier)
+        {
+            return Err(DexError::InvalidConfig);
+        }
+        if !(1..=60_000).contains(&self.frontrun_time_window_ms) {
+            return Err(DexError::InvalidConfig);
+        }
+        if !in_range(self.spoof_order_cancel_rate, 1, 100)
+            || !in_range(self.min_confidence_for_alert, 0, 100)
+            || !in_range(self.circular_volume_pct, 1, 100)
+        {
+            return Err(DexError::InvalidConfig);
+        }
+        if !in_range(self.price_deviation_bps, 1, 10_000)
+            || !in_range(self.flash_liquidity_bps, 1, 10_000)
+            || !in_range(self.pump_price_change_bps, self.price_deviation_bps + 1, 100_000)
+        {
+            return Err(DexError::InvalidConfig);
+        }
+        if !in_range(self.layering_min_levels, 2, 100)
+            || !in_range(self.quote_stuffing_msgs_per_sec, 1, 10_000)
+        {
+            return Err(DexError::InvalidConfig);
+        }
+        Ok(())
+    }
 }
 
 impl<'a> PatternDetector<'a> {
@@ -294,6 +406,31 @@ impl<'a> PatternDetector<'a> {
         indicators
     }
 
+    /// `analyze` plus the detectors that need order flow from the indexer
+    pub fn analyze_with_order_flow(
+        &self,
+        trading_data: &TradingData,
+        order_flow: &OrderFlowStats,
+    ) -> Vec<FraudIndicator> {
+        let mut indicators = self.analyze(trading_data);
+
+        let detected = [
+            self.detect_frontrunning(order_flow),
+            self.detect_spoofing(order_flow),
+            self.detect_layering(order_flow),
+            self.detect_momentum_ignition(order_flow),
+            self.detect_pump_and_dump(trading_data, order_flow),
+            self.detect_quote_stuffing(order_flow),
+            self.detect_circular_trading(order_flow),
+            self.detect_liquidity_manipulation(order_flow),
+        ];
+        for indicator in detected.into_iter().flatten() {
+            indicators.push_back(indicator);
+        }
+
+        indicators
+    }
+
     fn detect_wash_trading(&self, data: &TradingData) -> Option<FraudIndicator> {
         let volume_ratio = if data.volume.avg_trade_size > 0 {
             (data.volume.volume_usd * 100) / data.volume.avg_trade_size
@@ -382,17 +519,397 @@ impl<'a> PatternDetector<'a> {
         None
     }
 
-    pub fn detect_patterns(&self, trading_data: &TradingData) -> Vec<TradingPattern> {
+    fn detect_frontrunning(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        let window = self.thresholds.frontrun_time_window_ms;
+        if flow.shadowed_trades == 0 || window == 0 || flow.min_shadow_gap_ms > window {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::Frontrunning,
+            Severity::High,
+            IndicatorType::Frontrunning.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "shadow_gap_ms",
+            flow.min_shadow_gap_ms as i128,
+            window as i128,
+            "trade_ahead_of_order",
+        ));
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "shadowed_trades",
+            flow.shadowed_trades as i128,
+            0,
+            "repeated_frontrun",
+        ));
+
+        // Tighter gaps and repeated occurrences are less likely to be coincidence
+        let gap_confidence = ((window - flow.min_shadow_gap_ms).saturating_mul(50) / window) as u32;
+        let repeat_confidence = flow.shadowed_trades.saturating_mul(10).min(50);
+        indicator = indicator.with_confidence(gap_confidence + repeat_confidence);
+        indicator = indicator.with_impact(Severity::High.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_spoofing(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        let cancel_rate = flow.cancel_rate();
+        if flow.orders_placed == 0 || cancel_rate < self.thresholds.spoof_order_cancel_rate {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::Spoofing,
+            Severity::High,
+            IndicatorType::Spoofing.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "cancel_rate",
+            cancel_rate as i128,
+            self.thresholds.spoof_order_cancel_rate as i128,
+            "orders_cancelled",
+        ));
+
+        indicator = indicator.with_confidence(Self::confidence_above(
+            cancel_rate as i128,
+            self.thresholds.spoof_order_cancel_rate as i128,
+        ));
+        indicator = indicator.with_impact(Severity::High.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_layering(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        let min_levels = self.thresholds.layering_min_levels;
+        if min_levels == 0 || flow.max_layered_levels < min_levels {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::Layering,
+            Severity::Medium,
+            IndicatorType::Layering.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "layered_levels",
+            flow.max_layered_levels as i128,
+            min_levels as i128,
+            "stacked_orders",
+        ));
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "cancel_rate",
+            flow.cancel_rate() as i128,
+            self.thresholds.spoof_order_cancel_rate as i128,
+            "orders_cancelled",
+        ));
+
+        // Layers that are mostly pulled again are far more suspicious than resting depth
+        let mut confidence =
+            Self::confidence_above(flow.max_layered_levels as i128, min_levels as i128);
+        if flow.cancel_rate() < self.thresholds.spoof_order_cancel_rate {
+            confidence /= 2;
+        }
+        indicator = indicator.with_confidence(confidence);
+        indicator = indicator.with_impact(Severity::Medium.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_momentum_ignition(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        let run_up = flow.run_up_bps.unsigned_abs();
+        if run_up < self.thresholds.price_deviation_bps
+            || run_up >= self.thresholds.pump_price_change_bps
+            || !Self::is_reversed(flow)
+        {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::MomentumIgnition,
+            Severity::Medium,
+            IndicatorType::MomentumIgnition.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "run_up_bps",
+            run_up as i128,
+            self.thresholds.price_deviation_bps as i128,
+            "sharp_price_move",
+        ));
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "reversal_bps",
+            flow.reversal_bps.unsigned_abs() as i128,
+            (run_up / 2) as i128,
+            "move_reversed",
+        ));
+
+        indicator = indicator.with_confidence(Self::reversal_confidence(flow));
+        indicator = indicator.with_impact(Severity::Medium.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_pump_and_dump(
+        &self,
+        data: &TradingData,
+        flow: &OrderFlowStats,
+    ) -> Option<FraudIndicator> {
+        let run_up = flow.run_up_bps.unsigned_abs();
+        if flow.run_up_bps <= 0
+            || run_up < self.thresholds.pump_price_change_bps
+            || !Self::is_reversed(flow)
+        {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::PumpAndDump,
+            Severity::Critical,
+            IndicatorType::PumpAndDump.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "pump_bps",
+            run_up as i128,
+            self.thresholds.pump_price_change_bps as i128,
+            "price_pumped",
+        ));
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "dump_bps",
+            flow.reversal_bps.unsigned_abs() as i128,
+            (run_up / 2) as i128,
+            "price_dumped",
+        ));
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "volume_usd",
+            data.volume.volume_usd,
+            0,
+            "volume_during_pump",
+        ));
+
+        indicator = indicator.with_confidence(Self::reversal_confidence(flow));
+        indicator = indicator.with_impact(Severity::Critical.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_quote_stuffing(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        let threshold = self.thresholds.quote_stuffing_msgs_per_sec;
+        if threshold == 0 || flow.peak_messages_per_sec < threshold {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::QuoteStuffing,
+            Severity::Medium,
+            IndicatorType::QuoteStuffing.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "msgs_per_sec",
+            flow.peak_messages_per_sec as i128,
+            threshold as i128,
+            "order_message_burst",
+        ));
+
+        indicator = indicator.with_confidence(Self::confidence_above(
+            flow.peak_messages_per_sec as i128,
+            threshold as i128,
+        ));
+        indicator = indicator.with_impact(Severity::Medium.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_circular_trading(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        if flow.traded_volume <= 0 || flow.circular_volume <= 0 {
+            return None;
+        }
+
+        let circular_pct =
+            flow.circular_volume.min(flow.traded_volume).saturating_mul(100) / flow.traded_volume;
+        let threshold = self.thresholds.circular_volume_pct as i128;
+        if circular_pct < threshold {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::CircularTrading,
+            Severity::High,
+            IndicatorType::CircularTrading.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "circular_pct",
+            circular_pct,
+            threshold,
+            "volume_returned_to_origin",
+        ));
+
+        indicator = indicator.with_confidence(Self::confidence_above(circular_pct, threshold));
+        indicator = indicator.with_impact(Severity::High.score_penalty());
+
+        Some(indicator)
+    }
+
+    fn detect_liquidity_manipulation(&self, flow: &OrderFlowStats) -> Option<FraudIndicator> {
+        if flow.pool_liquidity <= 0 || flow.flash_liquidity <= 0 {
+            return None;
+        }
+
+        let flash_bps = flow.flash_liquidity.saturating_mul(10000) / flow.pool_liquidity;
+        let threshold = self.thresholds.flash_liquidity_bps as i128;
+        if flash_bps < threshold {
+            return None;
+        }
+
+        let mut indicator = FraudIndicator::new(
+            self.env,
+            IndicatorType::LiquidityManipulation,
+            Severity::High,
+            IndicatorType::LiquidityManipulation.description(),
+        );
+
+        indicator.add_evidence(Evidence::new(
+            self.env,
+            "flash_liquidity_bps",
+            flash_bps,
+            threshold,
+            "liquidity_added_and_pulled",
+        ));
+
+        indicator = indicator.with_confidence(Self::confidence_above(flash_bps, threshold));
+        indicator = indicator.with_impact(Severity::High.score_penalty());
+
+        Some(indicator)
+    }
+
+    /// 50 at the threshold, rising to 100 at twice the threshold
+    fn confidence_above(value: i128, threshold: i128) -> u32 {
+        if threshold <= 0 {
+            return 100;
+        }
+        (50 + value.saturating_sub(threshold).saturating_mul(50) / threshold).clamp(0, 100) as u32
+    }
+
+    /// The move gave back at least half of its run-up, in the opposite direction
+    fn is_reversed(flow: &OrderFlowStats) -> bool {
+        let opposite = (flow.run_up_bps > 0 && flow.reversal_bps < 0)
+            || (flow.run_up_bps < 0 && flow.reversal_bps > 0);
+        opposite
+            && flow.reversal_bps.unsigned_abs().saturating_mul(2) >= flow.run_up_bps.unsigned_abs()
+    }
+
+    /// Share of the run-up that was given back, capped at 100
+    fn reversal_confidence(flow: &OrderFlowStats) -> u32 {
+        let run_up = flow.run_up_bps.unsigned_abs().max(1);
+        (flow.reversal_bps.unsigned_abs() as u64 * 100 / run_up as u64).min(100) as u32
+    }
+
+    /// Sequence patterns over the pair's trade stream, using the `flow` already
+    /// computed from it for the indicators. Falls back to the aggregate trade
+    /// count when no individual trades have been indexed.
+    pub fn detect_patterns(
+        &self,
+        trading_data: &TradingData,
+        stream: &TradeStream,
+        flow: &OrderFlowStats,
+    ) -> Vec<TradingPattern> {
         let mut patterns = Vec::new(self.env);
 
-        let mut hf_pattern = TradingPattern::new(self.env, PatternType::HighFrequencySequence);
-        if trading_data.volume.trade_count > 100 {
-            hf_pattern.mark_suspicious(60);
+        if stream.is_empty() {
+            let mut hf_pattern = TradingPattern::new(self.env, PatternType::HighFrequencySequence);
+            if trading_data.volume.trade_count > 100 {
+                hf_pattern.mark_suspicious(60);
+            }
+            patterns.push_back(hf_pattern);
+            return patterns;
+        }
+
+        let start = stream.first_timestamp_ms() / 1000;
+        let end = stream.last_timestamp_ms() / 1000;
+        let trades = stream.len() as u64;
+        let volume = flow.traded_volume;
+        let participants = stream.participant_count(self.env);
+
+        let mut hf_pattern = TradingPattern::new(self.env, PatternType::HighFrequencySequence)
+            .with_period(start, end)
+            .with_stats(trades, volume, flow.peak_messages_per_sec as i128);
+        if flow.peak_messages_per_sec >= self.thresholds.quote_stuffing_msgs_per_sec {
+            hf_pattern.mark_suspicious(Self::confidence_above(
+                flow.peak_messages_per_sec as i128,
+                self.thresholds.quote_stuffing_msgs_per_sec as i128,
+            ));
         }
         patterns.push_back(hf_pattern);
 
-        let volume_pattern = TradingPattern::new(self.env, PatternType::VolumeSpike);
-        patterns.push_back(volume_pattern);
+        let round_trip_pct = flow.circular_volume.saturating_mul(100) / volume.max(1);
+        let mut round_trip = TradingPattern::new(self.env, PatternType::RoundTripSequence)
+            .with_period(start, end)
+            .with_stats(trades, flow.circular_volume, round_trip_pct);
+        if flow.circular_volume > 0
+            && round_trip_pct >= self.thresholds.circular_volume_pct as i128
+        {
+            round_trip.mark_suspicious(Self::confidence_above(
+                round_trip_pct,
+                self.thresholds.circular_volume_pct as i128,
+            ));
+        }
+        patterns.push_back(round_trip);
+
+        // Largest trade relative to the average trade, in percent
+        let avg_size = volume / trades as i128;
+        let spike_ratio = stream.largest_trade() * 100 / avg_size.max(1);
+        let mut volume_spike = TradingPattern::new(self.env, PatternType::VolumeSpike)
+            .with_period(start, end)
+            .with_stats(trades, volume, spike_ratio);
+        if spike_ratio > self.thresholds.unusual_volume_multiplier {
+            volume_spike.mark_suspicious(Self::confidence_above(
+                spike_ratio,
+                self.thresholds.unusual_volume_multiplier,
+            ));
+        }
+        patterns.push_back(volume_spike);
+
+        let move_bps = flow.run_up_bps.unsigned_abs();
+        let mut price_spike = TradingPattern::new(self.env, PatternType::PriceSpike)
+            .with_period(start, end)
+            .with_stats(trades, volume, flow.run_up_bps as i128);
+        if move_bps > self.thresholds.price_deviation_bps {
+            price_spike.mark_suspicious(Self::confidence_above(
+                move_bps as i128,
+                self.thresholds.price_deviation_bps as i128,
+            ));
+        }
+        patterns.push_back(price_spike);
+
+        for i in 0..patterns.len() {
+            let mut pattern = patterns.get(i).unwrap();
+            pattern.participant_count = participants;
+            patterns.set(i, pattern);
+        }
 
         patterns
     }
@@ -7,11 +7,18 @@ pub mod liquidity;
 pub mod cache;
 pub mod scoring_signals;
 pub mod fraud_indicators;
+pub mod account_activity;
+pub mod trade_stream;
 
 pub use adapter::{DexAdapter, DexConfig, DexError, TokenPair};
 pub use stellar_dex::StellarDexAdapter;
 pub use trading_data::{TradingData, TradingVolume, PriceData, SlippageData};
 pub use liquidity::{LiquidityMetrics, LiquidityDepth, PoolInfo};
 pub use cache::{DexDataCache, CacheEntry, CacheConfig};
-pub use scoring_signals::{ScoringSignal, SignalWeight, SignalAggregator};
+pub use scoring_signals::{
+    ScoringSignal, SignalWeight, SignalWeightSet, SignalWeightStore, SignalAggregator,
+    PairContribution, MultiPairScore,
+};
 pub use fraud_indicators::{FraudIndicator, TradingPattern, PatternDetector};
+pub use account_activity::{AccountTrade, AccountTradingLedger};
+pub use trade_stream::{OrderBookSummary, TradeRecord, TradeStream};
@@ -1,5 +1,6 @@
 use soroban_sdk::{contracttype, Env, Symbol, Vec};
 
+use super::account_activity::AccountTradingLedger;
 use super::adapter::TokenPair;
 use super::liquidity::LiquidityMetrics;
 use super::trading_data::TradingData;
@@ -129,15 +130,76 @@ impl SignalWeight {
         weights
     }
 
+    /// `decay` is the share of the weight (bps) lost per day of signal age
     pub fn with_decay(mut self, decay: u32) -> Self {
         self.decay_factor = decay;
         self
     }
+
+    /// `weight` reduced by this type's decay for a signal observed `age_seconds` ago
+    pub fn apply_decay(&self, weight: u32, age_seconds: u64) -> u32 {
+        let age_days = age_seconds / 86400;
+        let reduction = (self.decay_factor as u64 * age_days).min(10000);
+        (weight as u64 * (10000 - reduction) / 10000) as u32
+    }
+}
+
+/// A versioned set of signal weights. Version 0 is the built-in default set.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignalWeightSet {
+    pub version: u32,
+    pub weights: Vec<SignalWeight>,
+    pub updated_at: u64,
+}
+
+pub struct SignalWeightStore;
+
+impl SignalWeightStore {
+    /// Persist a new weight set and make it current. Returns the new version.
+    pub fn save(env: &Env, weights: Vec<SignalWeight>) -> u32 {
+        let version = Self::current(env).version + 1;
+        let set = SignalWeightSet {
+            version,
+            weights,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignalWeightSet(version), &set);
+        env.storage().instance().set(&DataKey::SignalWeights, &set);
+        version
+    }
+
+    pub fn current(env: &Env) -> SignalWeightSet {
+        env.storage()
+            .instance()
+            .get(&DataKey::SignalWeights)
+            .unwrap_or_else(|| Self::default_set(env))
+    }
+
+    pub fn get(env: &Env, version: u32) -> Option<SignalWeightSet> {
+        if version == 0 {
+            return Some(Self::default_set(env));
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignalWeightSet(version))
+    }
+
+    fn default_set(env: &Env) -> SignalWeightSet {
+        SignalWeightSet {
+            version: 0,
+            weights: SignalWeight::default_weights(env),
+            updated_at: 0,
+        }
+    }
 }
 
 pub struct SignalAggregator<'a> {
     env: &'a Env,
     weights: Vec<SignalWeight>,
+    version: u32,
 }
 
 impl<'a> SignalAggregator<'a> {
@@ -145,11 +207,31 @@ impl<'a> SignalAggregator<'a> {
         Self {
             env,
             weights: SignalWeight::default_weights(env),
+            version: 0,
         }
     }
 
     pub fn with_weights(env: &'a Env, weights: Vec<SignalWeight>) -> Self {
-        Self { env, weights }
+        Self {
+            env,
+            weights,
+            version: 0,
+        }
+    }
+
+    /// Aggregator using the admin-configured weight set, falling back to defaults
+    pub fn load(env: &'a Env) -> Self {
+        let set = SignalWeightStore::current(env);
+        Self {
+            env,
+            weights: set.weights,
+            version: set.version,
+        }
+    }
+
+    /// Version of the weight set this aggregator scores with
+    pub fn version(&self) -> u32 {
+        self.version
     }
 
     pub fn aggregate(
@@ -224,18 +306,90 @@ impl<'a> SignalAggregator<'a> {
         signals
     }
 
+    /// Signals derived from the account's own trades rather than pool-level data
+    pub fn aggregate_account(&self, ledger: &AccountTradingLedger) -> Vec<ScoringSignal> {
+        let mut signals = Vec::new(self.env);
+        if ledger.trade_count == 0 {
+            return signals;
+        }
+
+        let mut volume_signal = ScoringSignal::new(
+            self.env,
+            SignalType::TradingVolume,
+            ledger.total_volume_usd,
+            self.get_weight(&SignalType::TradingVolume),
+        );
+        let vol_weight = self.find_weight(&SignalType::TradingVolume);
+        volume_signal.normalize(vol_weight.min_value, vol_weight.max_value);
+        volume_signal.source = Symbol::new(self.env, "account");
+        signals.push_back(volume_signal);
+
+        let mut frequency_signal = ScoringSignal::new(
+            self.env,
+            SignalType::TradeFrequency,
+            ledger.trade_count as i128,
+            self.get_weight(&SignalType::TradeFrequency),
+        );
+        let freq_weight = self.find_weight(&SignalType::TradeFrequency);
+        frequency_signal.normalize(freq_weight.min_value, freq_weight.max_value);
+        frequency_signal.source = Symbol::new(self.env, "account");
+        signals.push_back(frequency_signal);
+
+        let mut slippage_signal = ScoringSignal::new(
+            self.env,
+            SignalType::SlippageTolerance,
+            (5000 - ledger.avg_slippage_bps() as i128).max(0),
+            self.get_weight(&SignalType::SlippageTolerance),
+        );
+        let slip_weight = self.find_weight(&SignalType::SlippageTolerance);
+        slippage_signal.normalize(slip_weight.min_value, slip_weight.max_value);
+        slippage_signal.source = Symbol::new(self.env, "account");
+        signals.push_back(slippage_signal);
+
+        signals
+    }
+
+    /// Pool-level signals with volume, frequency and slippage replaced by the account's own
+    pub fn aggregate_with_account(
+        &self,
+        trading_data: &TradingData,
+        liquidity: &LiquidityMetrics,
+        ledger: &AccountTradingLedger,
+    ) -> Vec<ScoringSignal> {
+        let account_signals = self.aggregate_account(ledger);
+        if account_signals.is_empty() {
+            return self.aggregate(trading_data, liquidity);
+        }
+
+        let mut signals = account_signals.clone();
+        for signal in self.aggregate(trading_data, liquidity).iter() {
+            let replaced = account_signals
+                .iter()
+                .any(|s| s.signal_type == signal.signal_type);
+            if !replaced {
+                signals.push_back(signal);
+            }
+        }
+        signals
+    }
+
     pub fn calculate_score(&self, signals: &Vec<ScoringSignal>) -> u32 {
         if signals.is_empty() {
             return 0;
         }
 
-        let total_weight: u32 = signals.iter().map(|s| s.weight).sum();
+        let now = self.env.ledger().timestamp();
+        let mut total_weight: u32 = 0;
+        let mut weighted_sum: u64 = 0;
+        for signal in signals.iter() {
+            let weight = self.effective_weight(&signal, now);
+            total_weight += weight;
+            weighted_sum += signal.normalized_score as u64 * weight as u64 / 100;
+        }
         if total_weight == 0 {
             return 0;
         }
 
-        let weighted_sum: u64 = signals.iter().map(|s| s.weighted_score() as u64).sum();
-
         let total_weight_u64 = total_weight as u64;
         let adjusted_weight = if total_weight_u64 > 100 {
             100
@@ -249,30 +403,112 @@ impl<'a> SignalAggregator<'a> {
     pub fn calculate_credit_impact(&self, base_score: u32, signals: &Vec<ScoringSignal>) -> i32 {
         let dex_score = self.calculate_score(signals);
 
-        let deviation = if dex_score > 50 {
-            (dex_score - 50) as i32
+        let adjusted_base = base_score as i32 + Self::score_adjustment(dex_score);
+        adjusted_base.clamp(0, 1000)
+    }
+
+    /// Credit score points for a 0-100 DEX score: 20% of its deviation from neutral (50)
+    pub fn score_adjustment(dex_score: u32) -> i32 {
+        (dex_score as i32 - 50) / 5
+    }
+
+    /// Credit score points each signal falls short of a perfect normalized score,
+    /// in the same order as `signals`
+    pub fn point_shortfalls(&self, signals: &Vec<ScoringSignal>) -> Vec<u32> {
+        let mut shortfalls = Vec::new(self.env);
+
+        let now = self.env.ledger().timestamp();
+        let mut total_weight: u64 = 0;
+        for signal in signals.iter() {
+            total_weight += self.effective_weight(&signal, now) as u64;
+        }
+        let adjusted_weight = total_weight.min(100).max(1);
+
+        for signal in signals.iter() {
+            let weight = self.effective_weight(&signal, now) as u64;
+            let missing = 100 - signal.normalized_score.min(100) as u64;
+            shortfalls.push_back((missing * weight / adjusted_weight / 5) as u32);
+        }
+        shortfalls
+    }
+
+    /// Score one pair and record the inputs used to weigh it against other pairs
+    pub fn score_pair(
+        &self,
+        trading_data: &TradingData,
+        liquidity: &LiquidityMetrics,
+    ) -> PairContribution {
+        let signals = self.aggregate(trading_data, liquidity);
+        let dex_score = self.calculate_score(&signals);
+
+        PairContribution {
+            pair: trading_data.pair.clone(),
+            dex_score,
+            volume_usd: trading_data.volume.volume_usd,
+            liquidity_health: liquidity.liquidity_health_score(),
+            weight_bps: 0,
+            adjustment: Self::score_adjustment(dex_score),
+        }
+    }
+
+    /// Combine per-pair results, weighting each pair by volume x liquidity health
+    pub fn combine_pairs(&self, base_score: u32, pairs: &Vec<PairContribution>) -> MultiPairScore {
+        let mut total_weight: i128 = 0;
+        for pair in pairs.iter() {
+            total_weight += Self::pair_weight(&pair);
+        }
+
+        let mut weighted: Vec<PairContribution> = Vec::new(self.env);
+        let mut weighted_score: i128 = 0;
+        for mut pair in pairs.iter() {
+            let weight = Self::pair_weight(&pair);
+            pair.weight_bps = if total_weight > 0 {
+                (weight * 10_000 / total_weight) as u32
+            } else {
+                0
+            };
+            weighted_score += pair.dex_score as i128 * weight;
+            weighted.push_back(pair);
+        }
+
+        let combined_adjustment = if total_weight > 0 {
+            Self::score_adjustment((weighted_score / total_weight) as u32)
         } else {
-            -((50 - dex_score) as i32)
+            0
         };
 
-        let impact = (deviation as f64 * 0.2) as i32;
+        MultiPairScore {
+            base_score,
+            combined_adjustment,
+            adjusted_score: (base_score as i32 + combined_adjustment).clamp(0, 1000) as u32,
+            pairs: weighted,
+        }
+    }
 
-        let adjusted_base = base_score as i32 + impact;
-        adjusted_base.clamp(0, 1000)
+    fn pair_weight(pair: &PairContribution) -> i128 {
+        pair.volume_usd.max(0) * pair.liquidity_health as i128 / 100
+    }
+
+    /// A signal's weight after applying its type's decay for the signal's age
+    fn effective_weight(&self, signal: &ScoringSignal, now: u64) -> u32 {
+        self.find_weight(&signal.signal_type)
+            .apply_decay(signal.weight, now.saturating_sub(signal.timestamp))
     }
 
     fn get_weight(&self, signal_type: &SignalType) -> u32 {
         self.find_weight(signal_type).weight
     }
 
-    fn find_weight(&self, signal_type: &SignalType) -> &SignalWeight {
+    /// The configured weight for `signal_type`. A type missing from the set
+    /// gets weight 0, so it is reported but does not move the score.
+    fn find_weight(&self, signal_type: &SignalType) -> SignalWeight {
         for weight in self.weights.iter() {
             if weight.signal_type == *signal_type {
-                return &weight;
+                return weight;
             }
         }
 
-        &self.weights.get(0).unwrap()
+        SignalWeight::new(signal_type.clone(), 0, 0, 10000)
     }
 
     pub fn signal_summary(&self, signals: &Vec<ScoringSignal>) -> SignalSummary {
@@ -310,6 +546,27 @@ impl<'a> SignalAggregator<'a> {
     }
 }
 
+/// One pair's share of a multi-pair DEX score
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PairContribution {
+    pub pair: TokenPair,
+    pub dex_score: u32,
+    pub volume_usd: i128,
+    pub liquidity_health: u32,
+    pub weight_bps: u32,
+    pub adjustment: i32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiPairScore {
+    pub base_score: u32,
+    pub combined_adjustment: i32,
+    pub adjusted_score: u32,
+    pub pairs: Vec<PairContribution>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignalSummary {
@@ -346,4 +603,5 @@ pub enum RiskLevel {
 pub enum DataKey {
     SignalWeights,
     SignalHistory(TokenPair, u64),
+    SignalWeightSet(u32),
 }
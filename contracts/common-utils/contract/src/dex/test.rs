@@ -55,6 +55,26 @@ fn test_dex_config_validation() {
     assert!(invalid_config2.validate().is_err());
 }
 
+#[test]
+fn test_detection_thresholds_validation() {
+    use super::fraud_indicators::DetectionThresholds;
+
+    assert!(DetectionThresholds::default().validate().is_ok());
+
+    let mut thresholds = DetectionThresholds::default();
+    thresholds.min_confidence_for_alert = 500;
+    assert_eq!(thresholds.validate(), Err(DexError::InvalidConfig));
+
+    let mut thresholds = DetectionThresholds::default();
+    thresholds.unusual_volume_multiplier = 0;
+    assert_eq!(thresholds.validate(), Err(DexError::InvalidConfig));
+
+    // The pump threshold must sit above the momentum threshold
+    let mut thresholds = DetectionThresholds::default();
+    thresholds.pump_price_change_bps = thresholds.price_deviation_bps;
+    assert_eq!(thresholds.validate(), Err(DexError::InvalidConfig));
+}
+
 #[test]
 fn test_dex_registry_creation() {
     let stellar_dex = DexRegistry::new_stellar_dex();
@@ -77,3 +97,302 @@ fn test_dex_error_codes() {
     assert_eq!(DexError::InvalidTokenPair as u32, 2003);
     assert_eq!(DexError::CacheExpired as u32, 2008);
 }
+
+#[test]
+fn test_multi_pair_weighting() {
+    use super::scoring_signals::PairContribution;
+
+    let env = Env::default();
+    let pair_a = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "USDC");
+    let pair_b = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "BTC");
+
+    let mut pairs = soroban_sdk::Vec::new(&env);
+    pairs.push_back(PairContribution {
+        pair: pair_a,
+        dex_score: 100,
+        volume_usd: 3_000_000,
+        liquidity_health: 100,
+        weight_bps: 0,
+        adjustment: SignalAggregator::score_adjustment(100),
+    });
+    pairs.push_back(PairContribution {
+        pair: pair_b,
+        dex_score: 0,
+        volume_usd: 2_000_000,
+        liquidity_health: 50,
+        weight_bps: 0,
+        adjustment: SignalAggregator::score_adjustment(0),
+    });
+
+    let aggregator = SignalAggregator::new(&env);
+    let result = aggregator.combine_pairs(600, &pairs);
+
+    // Weights 3M and 1M -> 75% / 25%, combined DEX score 75 -> +5 points
+    assert_eq!(result.pairs.get(0).unwrap().weight_bps, 7500);
+    assert_eq!(result.pairs.get(1).unwrap().weight_bps, 2500);
+    assert_eq!(result.combined_adjustment, 5);
+    assert_eq!(result.adjusted_score, 605);
+
+    let empty = aggregator.combine_pairs(600, &soroban_sdk::Vec::new(&env));
+    assert_eq!(empty.combined_adjustment, 0);
+    assert_eq!(empty.adjusted_score, 600);
+}
+
+#[test]
+fn test_signal_weight_decay() {
+    use super::scoring_signals::SignalType;
+
+    let weight = SignalWeight::new(SignalType::TradingVolume, 40, 0, 100).with_decay(1000);
+
+    assert_eq!(weight.apply_decay(40, 0), 40);
+    assert_eq!(weight.apply_decay(40, 86_399), 40);
+    assert_eq!(weight.apply_decay(40, 2 * 86_400), 32);
+    assert_eq!(weight.apply_decay(40, 30 * 86_400), 0);
+
+    let no_decay = SignalWeight::new(SignalType::TradingVolume, 40, 0, 100);
+    assert_eq!(no_decay.apply_decay(40, 365 * 86_400), 40);
+}
+
+#[test]
+fn test_missing_signal_types_weigh_nothing() {
+    use super::scoring_signals::{ScoringSignal, SignalType};
+
+    let env = Env::default();
+    let mut weights = soroban_sdk::Vec::new(&env);
+    weights.push_back(SignalWeight::new(SignalType::TradingVolume, 60, 0, 100));
+    weights.push_back(SignalWeight::new(SignalType::PriceStability, 40, 0, 100));
+    let aggregator = SignalAggregator::with_weights(&env, weights);
+
+    let signal = |signal_type: SignalType, normalized_score: u32| ScoringSignal {
+        signal_type,
+        value: 0,
+        normalized_score,
+        weight: 0,
+        confidence: 100,
+        timestamp: 0,
+        source: Symbol::new(&env, "test"),
+    };
+    let mut signals = soroban_sdk::Vec::new(&env);
+    signals.push_back(signal(SignalType::TradingVolume, 100));
+    signals.push_back(signal(SignalType::TradeFrequency, 0));
+
+    // TradeFrequency is not in the set, so it must not borrow TradingVolume's weight
+    assert_eq!(aggregator.calculate_score(&signals), 100);
+}
+
+#[test]
+fn test_order_flow_detectors() {
+    use super::fraud_indicators::{IndicatorType, OrderFlowStats};
+
+    let env = Env::default();
+    let pair = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "USDC");
+    let volume = TradingVolume::new(&env, pair.clone(), 1000, 1000, 1000, 1, 3600);
+    let price = PriceData::new(&env, pair.clone(), 1_000_000, 7, "test");
+    let data = TradingData::new(&env, pair, volume, price);
+    let detector = PatternDetector::new(&env);
+
+    let clean = OrderFlowStats::new(0, 3600);
+    assert!(detector.analyze_with_order_flow(&data, &clean).is_empty());
+
+    let mut flow = OrderFlowStats::new(0, 3600);
+    flow.shadowed_trades = 3;
+    flow.min_shadow_gap_ms = 200;
+    flow.orders_placed = 100;
+    flow.orders_cancelled = 95;
+    flow.max_layered_levels = 6;
+    flow.peak_messages_per_sec = 120;
+    flow.run_up_bps = 3000;
+    flow.reversal_bps = -2000;
+    flow.traded_volume = 1000;
+    flow.circular_volume = 300;
+    flow.pool_liquidity = 10_000;
+    flow.flash_liquidity = 2500;
+
+    let indicators = detector.analyze_with_order_flow(&data, &flow);
+    let fired: soroban_sdk::Vec<IndicatorType> =
+        indicators.iter().fold(soroban_sdk::Vec::new(&env), |mut types, indicator| {
+            assert!(!indicator.evidence.is_empty());
+            assert!(indicator.confidence > 0);
+            types.push_back(indicator.indicator_type);
+            types
+        });
+
+    assert!(fired.contains(&IndicatorType::Frontrunning));
+    assert!(fired.contains(&IndicatorType::Spoofing));
+    assert!(fired.contains(&IndicatorType::Layering));
+    assert!(fired.contains(&IndicatorType::PumpAndDump));
+    assert!(fired.contains(&IndicatorType::QuoteStuffing));
+    assert!(fired.contains(&IndicatorType::CircularTrading));
+    assert!(fired.contains(&IndicatorType::LiquidityManipulation));
+    // A run-up this large is classified as a pump, not momentum ignition
+    assert!(!fired.contains(&IndicatorType::MomentumIgnition));
+
+    flow.run_up_bps = -800;
+    flow.reversal_bps = 600;
+    let indicators = detector.analyze_with_order_flow(&data, &flow);
+    let momentum = indicators
+        .iter()
+        .find(|indicator| indicator.indicator_type == IndicatorType::MomentumIgnition)
+        .unwrap();
+    assert_eq!(momentum.confidence, 75);
+    assert!(!indicators
+        .iter()
+        .any(|indicator| indicator.indicator_type == IndicatorType::PumpAndDump));
+}
+
+#[test]
+fn test_trade_stream_sequences() {
+    use super::fraud_indicators::PatternType;
+    use super::trade_stream::{TradeRecord, MAX_TRADE_RECORDS};
+
+    let env = Env::default();
+    let pair = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "USDC");
+    let front = Address::generate(&env);
+    let victim = Address::generate(&env);
+    let trade = |trader: &Address, is_buy: bool, size: i128, price: i128, timestamp_ms: u64| TradeRecord {
+        trader: trader.clone(),
+        counterparty: None,
+        is_buy,
+        size,
+        price,
+        timestamp_ms,
+        cancels: 0,
+    };
+
+    // Buy ahead of a larger buy and sell straight after it
+    let mut stream = TradeStream::new(&env, pair.clone());
+    assert!(stream.append(trade(&front, true, 10, 1_000, 1_000)));
+    assert!(stream.append(trade(&victim, true, 100, 1_010, 1_200)));
+    assert!(stream.append(trade(&front, false, 10, 1_020, 1_500)));
+    assert!(!stream.append(trade(&victim, true, 10, 1_020, 1_400)));
+
+    let flow = stream.order_flow(&env, 1_000);
+    assert_eq!(flow.shadowed_trades, 1);
+    assert_eq!(flow.min_shadow_gap_ms, 200);
+    assert_eq!(flow.traded_volume, 120);
+    // Buying and selling back is not circular without a chain of counterparties
+    assert_eq!(flow.circular_volume, 0);
+    assert_eq!(flow.run_up_bps, 200);
+
+    // Outside the window the same sequence is not a frontrun
+    assert_eq!(stream.order_flow(&env, 100).shadowed_trades, 0);
+
+    let volume = TradingVolume::new(&env, pair.clone(), 120, 120, 120, 3, 1);
+    let price = PriceData::new(&env, pair.clone(), 1_020, 0, "test");
+    let data = TradingData::new(&env, pair.clone(), volume, price);
+    let patterns = PatternDetector::new(&env).detect_patterns(&data, &stream, &flow);
+    assert_eq!(patterns.len(), 4);
+    for pattern in patterns.iter() {
+        assert_eq!(pattern.trade_count, 3);
+        assert_eq!(pattern.participant_count, 2);
+        assert_eq!(pattern.start_time, 1);
+    }
+    let round_trip = patterns
+        .iter()
+        .find(|p| p.pattern_type == PatternType::RoundTripSequence)
+        .unwrap();
+    assert_eq!(round_trip.volume_ratio, 0);
+    assert!(!round_trip.is_suspicious);
+
+    // The buffer keeps only the newest records
+    let mut bounded = TradeStream::new(&env, pair);
+    for i in 0..(MAX_TRADE_RECORDS as u64 + 1) {
+        bounded.append(trade(&front, true, 1, 1_000, i));
+    }
+    assert_eq!(bounded.len(), MAX_TRADE_RECORDS);
+    assert_eq!(bounded.total_appended, MAX_TRADE_RECORDS as u64 + 1);
+    assert_eq!(bounded.first_timestamp_ms(), 1);
+}
+
+#[test]
+fn test_circular_volume_follows_counterparties() {
+    use super::trade_stream::TradeRecord;
+
+    let env = Env::default();
+    let pair = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "USDC");
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let maker = Address::generate(&env);
+    let sell = |seller: &Address, buyer: &Address, size: i128, timestamp_ms: u64| TradeRecord {
+        trader: seller.clone(),
+        counterparty: Some(buyer.clone()),
+        is_buy: false,
+        size,
+        price: 1_000,
+        timestamp_ms,
+        cancels: 0,
+    };
+
+    // A market maker buying from A and selling to B closes no cycle
+    let mut stream = TradeStream::new(&env, pair.clone());
+    stream.append(sell(&a, &maker, 100, 1_000));
+    stream.append(sell(&maker, &b, 100, 2_000));
+    assert_eq!(stream.circular_volume(&env), 0);
+
+    // The asset goes A -> B -> C and back to A
+    let mut stream = TradeStream::new(&env, pair.clone());
+    stream.append(sell(&a, &b, 100, 1_000));
+    stream.append(sell(&b, &c, 100, 2_000));
+    stream.append(sell(&maker, &b, 50, 2_500));
+    stream.append(sell(&c, &a, 100, 3_000));
+    let flow = stream.order_flow(&env, 1_000);
+    assert_eq!(flow.circular_volume, 100);
+    assert_eq!(flow.traded_volume, 350);
+
+    // The same transfers out of order form no chain
+    let mut stream = TradeStream::new(&env, pair);
+    stream.append(sell(&b, &c, 100, 1_000));
+    stream.append(sell(&a, &b, 100, 2_000));
+    stream.append(sell(&c, &a, 100, 3_000));
+    assert_eq!(stream.circular_volume(&env), 0);
+}
+
+#[test]
+fn test_order_flow_saturates_on_extreme_values() {
+    use super::fraud_indicators::{DetectionThresholds, IndicatorType, OrderFlowStats};
+    use super::trade_stream::TradeRecord;
+
+    let env = Env::default();
+    let pair = TokenPair::new(&env, Address::generate(&env), Address::generate(&env), "XLM", "USDC");
+    let trader = Address::generate(&env);
+    let trade = |price: i128, timestamp_ms: u64| TradeRecord {
+        trader: trader.clone(),
+        counterparty: None,
+        is_buy: true,
+        size: i128::MAX,
+        price,
+        timestamp_ms,
+        cancels: u32::MAX,
+    };
+
+    let mut stream = TradeStream::new(&env, pair.clone());
+    stream.append(trade(1, 1_000));
+    stream.append(trade(i128::MAX, 1_000));
+    let flow = stream.order_flow(&env, 1_000);
+    assert_eq!(flow.orders_placed, u32::MAX);
+    assert_eq!(flow.orders_cancelled, u32::MAX);
+    assert_eq!(flow.peak_messages_per_sec, u32::MAX);
+    assert_eq!(flow.traded_volume, i128::MAX);
+    assert_eq!(stream.traded_volume(), i128::MAX);
+    assert_eq!(flow.run_up_bps, i32::MAX);
+
+    let mut thresholds = DetectionThresholds::new();
+    thresholds.frontrun_time_window_ms = u64::MAX;
+    let detector = PatternDetector::with_thresholds(&env, thresholds);
+    let volume = TradingVolume::new(&env, pair.clone(), 1000, 1000, 1000, 1, 3600);
+    let price = PriceData::new(&env, pair.clone(), 1_000_000, 7, "test");
+    let data = TradingData::new(&env, pair, volume, price);
+
+    let mut flow = OrderFlowStats::new(0, 3600);
+    flow.shadowed_trades = u32::MAX;
+    flow.pool_liquidity = 1;
+    flow.flash_liquidity = i128::MAX;
+    let indicators = detector.analyze_with_order_flow(&data, &flow);
+    assert!(indicators
+        .iter()
+        .any(|indicator| indicator.indicator_type == IndicatorType::Frontrunning));
+    assert!(indicators
+        .iter()
+        .any(|indicator| indicator.indicator_type == IndicatorType::LiquidityManipulation));
+}
@@ -0,0 +1,384 @@
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+use super::adapter::TokenPair;
+use super::fraud_indicators::OrderFlowStats;
+
+/// Number of trades retained per pair; the oldest record is dropped once full
+pub const MAX_TRADE_RECORDS: u32 = 500;
+/// Upper limit on the traders returned by [`TradeStream::flagged_traders`]
+pub const MAX_FLAGGED_TRADERS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeRecord {
+    pub trader: Address,
+    /// Other side of the fill when the venue reports one (order book fills);
+    /// `None` for pool swaps
+    pub counterparty: Option<Address>,
+    pub is_buy: bool,
+    pub size: i128,
+    pub price: i128,
+    /// Milliseconds, so trades settled within the same ledger can still be ordered
+    pub timestamp_ms: u64,
+    /// Orders the trader cancelled on this pair since their previous trade
+    pub cancels: u32,
+}
+
+impl TradeRecord {
+    pub fn is_valid(&self) -> bool {
+        self.size > 0 && self.price > 0
+    }
+}
+
+/// Order book and pool state the trades themselves cannot show, summarized
+/// by the indexer over the same observation window as the retained trades
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBookSummary {
+    /// Most price levels a single trader held on one side at the same time
+    pub max_layered_levels: u32,
+    pub pool_liquidity: i128,
+    /// Liquidity that was both added and removed within the window
+    pub flash_liquidity: i128,
+    pub observed_at_ms: u64,
+}
+
+impl OrderBookSummary {
+    pub fn is_valid(&self) -> bool {
+        self.pool_liquidity >= 0 && self.flash_liquidity >= 0
+    }
+}
+
+/// Bounded, time-ordered stream of individual trades on one pair, appended
+/// to by an authorized indexer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeStream {
+    pub pair: TokenPair,
+    pub records: Vec<TradeRecord>,
+    /// Trades ever appended, including those already dropped from the buffer
+    pub total_appended: u64,
+    /// Latest order book summary; each new one replaces the previous
+    pub book: Option<OrderBookSummary>,
+}
+
+impl TradeStream {
+    pub fn new(env: &Env, pair: TokenPair) -> Self {
+        Self {
+            pair,
+            records: Vec::new(env),
+            total_appended: 0,
+            book: None,
+        }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Append `record`, evicting the oldest trade when the buffer is full.
+    /// Records older than the newest retained trade are refused.
+    pub fn append(&mut self, record: TradeRecord) -> bool {
+        if let Some(newest) = self.records.last() {
+            if record.timestamp_ms < newest.timestamp_ms {
+                return false;
+            }
+        }
+
+        if self.records.len() >= MAX_TRADE_RECORDS {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+        self.total_appended += 1;
+        true
+    }
+
+    pub fn first_timestamp_ms(&self) -> u64 {
+        self.records.first().map(|r| r.timestamp_ms).unwrap_or(0)
+    }
+
+    pub fn last_timestamp_ms(&self) -> u64 {
+        self.records.last().map(|r| r.timestamp_ms).unwrap_or(0)
+    }
+
+    pub fn traded_volume(&self) -> i128 {
+        let mut volume = 0i128;
+        for record in self.records.iter() {
+            volume = volume.saturating_add(record.size);
+        }
+        volume
+    }
+
+    pub fn largest_trade(&self) -> i128 {
+        let mut largest = 0;
+        for record in self.records.iter() {
+            largest = largest.max(record.size);
+        }
+        largest
+    }
+
+    pub fn participant_count(&self, env: &Env) -> u32 {
+        let mut traders: Map<Address, bool> = Map::new(env);
+        for record in self.records.iter() {
+            traders.set(record.trader, true);
+        }
+        traders.len()
+    }
+
+    /// Volume of fills whose asset came back to the seller through a chain of
+    /// later fills (A sells to B, B to C, ... back to A). Only fills with a
+    /// counterparty can form a chain, so a trader who merely buys and sells
+    /// against different accounts contributes nothing.
+    pub fn circular_volume(&self, env: &Env) -> i128 {
+        Tape::load(env, &self.records).circular_volume()
+    }
+
+    /// Traders behind the sequence indicators, in order of first appearance:
+    /// those who traded ahead of a frontrun trade, and the seller and first
+    /// buyer of each fill that came back around a cycle.
+    pub fn flagged_traders(&self, env: &Env, frontrun_window_ms: u64) -> Vec<Address> {
+        let mut tape = Tape::load(env, &self.records);
+        tape.find_frontruns(frontrun_window_ms);
+        tape.circular_volume();
+
+        let mut traders = Vec::new(env);
+        for (id, trader) in tape.participants.iter().enumerate() {
+            if traders.len() >= MAX_FLAGGED_TRADERS {
+                break;
+            }
+            if tape.flagged[id] {
+                traders.push_back(trader);
+            }
+        }
+        traders
+    }
+
+    /// Whether there is anything for the order flow detectors to look at
+    pub fn has_order_flow(&self) -> bool {
+        !self.records.is_empty() || self.book.is_some()
+    }
+
+    /// Summarize the buffer for the order flow detectors. A trade counts as
+    /// frontrun when another trader traded the same side just before it,
+    /// with a smaller size, and reversed just after it, all within
+    /// `frontrun_window_ms`.
+    ///
+    /// Resting order depth and pool liquidity are not part of the trades;
+    /// `max_layered_levels`, `pool_liquidity` and `flash_liquidity` come from
+    /// the indexer's [`OrderBookSummary`] and stay zero without one.
+    ///
+    /// This walks the whole buffer; compute it once per analysis and share it.
+    pub fn order_flow(&self, env: &Env, frontrun_window_ms: u64) -> OrderFlowStats {
+        let mut flow = OrderFlowStats::new(
+            self.first_timestamp_ms() / 1000,
+            self.last_timestamp_ms() / 1000,
+        );
+        if let Some(book) = &self.book {
+            flow.max_layered_levels = book.max_layered_levels;
+            flow.pool_liquidity = book.pool_liquidity;
+            flow.flash_liquidity = book.flash_liquidity;
+        }
+        let (open, close) = match (self.records.first(), self.records.last()) {
+            (Some(first), Some(last)) => (first.price, last.price),
+            _ => return flow,
+        };
+
+        let mut extreme = open;
+        let mut second = u64::MAX;
+        let mut messages = 0u32;
+        for record in self.records.iter() {
+            let record_messages = record.cancels.saturating_add(1);
+            flow.orders_placed = flow.orders_placed.saturating_add(record_messages);
+            flow.orders_cancelled = flow.orders_cancelled.saturating_add(record.cancels);
+            flow.traded_volume = flow.traded_volume.saturating_add(record.size);
+
+            if record.timestamp_ms / 1000 != second {
+                second = record.timestamp_ms / 1000;
+                messages = 0;
+            }
+            messages = messages.saturating_add(record_messages);
+            flow.peak_messages_per_sec = flow.peak_messages_per_sec.max(messages);
+
+            if (record.price - open).abs() > (extreme - open).abs() {
+                extreme = record.price;
+            }
+        }
+
+        let mut tape = Tape::load(env, &self.records);
+        flow.run_up_bps = Self::change_bps(open, extreme);
+        flow.reversal_bps = Self::change_bps(extreme, close);
+        flow.circular_volume = tape.circular_volume();
+
+        let (shadowed, min_gap) = tape.find_frontruns(frontrun_window_ms);
+        flow.shadowed_trades = shadowed;
+        flow.min_shadow_gap_ms = min_gap;
+
+        flow
+    }
+
+    /// Clamped to the `i32` range, which a price can leave on a thin pair
+    fn change_bps(from: i128, to: i128) -> i32 {
+        if from <= 0 {
+            return 0;
+        }
+        let bps = to.saturating_sub(from).saturating_mul(10000) / from;
+        bps.clamp(i32::MIN as i128, i32::MAX as i128) as i32
+    }
+}
+
+const TAPE_CAPACITY: usize = MAX_TRADE_RECORDS as usize;
+/// Each record can introduce a trader and a counterparty
+const MAX_PARTICIPANTS: usize = TAPE_CAPACITY * 2;
+const NO_COUNTERPARTY: u32 = u32::MAX;
+
+/// Native copy of the fields the sequence scans compare, loaded with one
+/// pass over the buffer. Traders are replaced by dense ids so the nested
+/// scans make no host calls; the scans mark the ids they implicate.
+struct Tape {
+    len: usize,
+    ids: Map<Address, u32>,
+    /// Address of each id
+    participants: Vec<Address>,
+    flagged: [bool; MAX_PARTICIPANTS],
+    trader: [u32; TAPE_CAPACITY],
+    counterparty: [u32; TAPE_CAPACITY],
+    is_buy: [bool; TAPE_CAPACITY],
+    size: [i128; TAPE_CAPACITY],
+    timestamp_ms: [u64; TAPE_CAPACITY],
+}
+
+impl Tape {
+    fn load(env: &Env, records: &Vec<TradeRecord>) -> Self {
+        let mut tape = Self {
+            len: 0,
+            ids: Map::new(env),
+            participants: Vec::new(env),
+            flagged: [false; MAX_PARTICIPANTS],
+            trader: [0; TAPE_CAPACITY],
+            counterparty: [NO_COUNTERPARTY; TAPE_CAPACITY],
+            is_buy: [false; TAPE_CAPACITY],
+            size: [0; TAPE_CAPACITY],
+            timestamp_ms: [0; TAPE_CAPACITY],
+        };
+        for record in records.iter().take(TAPE_CAPACITY) {
+            let i = tape.len;
+            tape.trader[i] = tape.id(record.trader);
+            if let Some(counterparty) = record.counterparty {
+                tape.counterparty[i] = tape.id(counterparty);
+            }
+            tape.is_buy[i] = record.is_buy;
+            tape.size[i] = record.size;
+            tape.timestamp_ms[i] = record.timestamp_ms;
+            tape.len += 1;
+        }
+        tape
+    }
+
+    fn id(&mut self, trader: Address) -> u32 {
+        match self.ids.get(trader.clone()) {
+            Some(id) => id,
+            None => {
+                let id = self.participants.len();
+                self.ids.set(trader.clone(), id);
+                self.participants.push_back(trader);
+                id
+            }
+        }
+    }
+
+    /// Seller and buyer of fill `i`, when its counterparty is known
+    fn transfer(&self, i: usize) -> Option<(u32, u32)> {
+        let counterparty = self.counterparty[i];
+        if counterparty == NO_COUNTERPARTY {
+            None
+        } else if self.is_buy[i] {
+            Some((counterparty, self.trader[i]))
+        } else {
+            Some((self.trader[i], counterparty))
+        }
+    }
+
+    /// For each fill, follow the asset forward through later fills and count
+    /// the fill once if it reaches the original seller. A self-trade closes
+    /// its cycle immediately.
+    fn circular_volume(&mut self) -> i128 {
+        // reached[id] holds the index of the fill whose chain last reached `id`
+        let mut reached = [u32::MAX; MAX_PARTICIPANTS];
+        let mut volume: i128 = 0;
+
+        for i in 0..self.len {
+            let (seller, buyer) = match self.transfer(i) {
+                Some(transfer) => transfer,
+                None => continue,
+            };
+            if seller == buyer {
+                volume = volume.saturating_add(self.size[i]);
+                self.flagged[seller as usize] = true;
+                continue;
+            }
+
+            let origin = i as u32;
+            reached[buyer as usize] = origin;
+            for j in (i + 1)..self.len {
+                if let Some((from, to)) = self.transfer(j) {
+                    if reached[from as usize] != origin {
+                        continue;
+                    }
+                    if to == seller {
+                        volume = volume.saturating_add(self.size[i]);
+                        self.flagged[seller as usize] = true;
+                        self.flagged[buyer as usize] = true;
+                        break;
+                    }
+                    reached[to as usize] = origin;
+                }
+            }
+        }
+
+        volume
+    }
+
+    /// Only trades within `window_ms` of each victim are compared
+    fn find_frontruns(&mut self, window_ms: u64) -> (u32, u64) {
+        let mut count = 0u32;
+        let mut min_gap = u64::MAX;
+
+        for j in 0..self.len {
+            for i in (0..j).rev() {
+                let gap = self.timestamp_ms[j] - self.timestamp_ms[i];
+                if gap > window_ms {
+                    break;
+                }
+                if self.trader[i] != self.trader[j]
+                    && self.is_buy[i] == self.is_buy[j]
+                    && self.size[i] < self.size[j]
+                    && self.reverses_after(self.trader[i], self.is_buy[i], j, window_ms)
+                {
+                    count += 1;
+                    min_gap = min_gap.min(gap);
+                    self.flagged[self.trader[i] as usize] = true;
+                    break;
+                }
+            }
+        }
+
+        (count, if count == 0 { 0 } else { min_gap })
+    }
+
+    fn reverses_after(&self, trader: u32, was_buy: bool, index: usize, window_ms: u64) -> bool {
+        let victim_at = self.timestamp_ms[index];
+        for k in (index + 1)..self.len {
+            if self.timestamp_ms[k] - victim_at > window_ms {
+                break;
+            }
+            if self.trader[k] == trader && self.is_buy[k] != was_buy {
+                return true;
+            }
+        }
+        false
+    }
+}
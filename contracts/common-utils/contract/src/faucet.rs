@@ -6,6 +6,7 @@ use soroban_sdk::{
     contract, contractimpl, panic_with_error, Symbol, Address, Env, contracterror, 
     contracttype, token,
 };
+use crate::watchlist;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -16,6 +17,7 @@ pub enum FaucetError {
     AddressLimitExceeded = 4,
     InvalidAmount = 5,
     UnauthorizedCaller = 6,
+    BlockedAddress = 7,
 }
 
 /// Faucet configuration stored in persistent storage
@@ -50,6 +52,10 @@ fn last_claim_key(address: &Address) -> (Symbol, Address) {
     (Symbol::new(&Env::default(), "faucet_last"), address.clone())
 }
 
+fn watchlist_key() -> Symbol {
+    Symbol::new(&Env::default(), "faucet_watch")
+}
+
 #[contractimpl]
 impl FaucetContract {
     /// Initialize the faucet contract
@@ -97,6 +103,9 @@ impl FaucetContract {
             .get(&config_key())
             .expect("Faucet not initialized");
 
+        // Reject addresses blocked on the watchlist, if one is configured
+        Self::check_watchlist(&env, &claimer);
+
         let now = env.ledger().timestamp();
 
         // Check rate limit (claims per window)
@@ -178,6 +187,26 @@ impl FaucetContract {
         );
     }
 
+    /// Reject claims from addresses blocked on `watchlist` (admin only). `None` disables the check.
+    pub fn set_watchlist(env: Env, admin: Address, watchlist: Option<Address>) {
+        admin.require_auth();
+
+        let config: FaucetConfig = env
+            .storage()
+            .persistent()
+            .get(&config_key())
+            .expect("Faucet not initialized");
+
+        if config.admin != admin {
+            panic_with_error!(&env, FaucetError::UnauthorizedCaller);
+        }
+
+        match watchlist {
+            Some(watchlist) => env.storage().persistent().set(&watchlist_key(), &watchlist),
+            None => env.storage().persistent().remove(&watchlist_key()),
+        }
+    }
+
     /// Get current faucet configuration
     pub fn get_config(env: Env) -> FaucetConfig {
         env.storage()
@@ -188,6 +217,15 @@ impl FaucetContract {
 
     // ==================== INTERNAL HELPERS ====================
 
+    /// Check the claimer against the configured watchlist
+    fn check_watchlist(env: &Env, address: &Address) {
+        if let Some(watchlist) = env.storage().persistent().get::<_, Address>(&watchlist_key()) {
+            if watchlist::is_blocked_on(env, &watchlist, address) {
+                panic_with_error!(env, FaucetError::BlockedAddress);
+            }
+        }
+    }
+
     /// Check if address exceeds rate limit for current window
     fn check_rate_limit(
         env: &Env,
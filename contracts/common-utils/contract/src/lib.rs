@@ -18,6 +18,7 @@ pub use error::CommonError;
 pub use state_machine::{State, StateMachine, FraudDetectState, RiskEvalState, CreditScoreState, state_guard, transition_to};
 pub mod acl;
 pub mod batch;
+pub mod watchlist;
 
 use soroban_sdk::{
     contract,
@@ -259,3 +260,6 @@ mod rate_limit_tests;
 
 #[cfg(test)]
 mod state_machine_tests;
+
+#[cfg(test)]
+mod watchlist_tests;
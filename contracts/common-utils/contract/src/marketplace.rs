@@ -1,5 +1,6 @@
 use soroban_sdk::{contract, contractimpl, Address, Env, token, contracttype, symbol_short};
 use crate::error::CommonError;
+use crate::watchlist;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -23,6 +24,8 @@ pub struct Listing {
 #[contracttype]
 pub enum DataKey {
     Listing(u64),
+    Admin,
+    Watchlist,
 }
 
 #[contract]
@@ -30,6 +33,30 @@ pub struct MarketplaceContract;
 
 #[contractimpl]
 impl MarketplaceContract {
+    /// Set the admin allowed to configure the marketplace. Runs once, as part
+    /// of the deployment, so no later caller can claim the admin role.
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Reject buyers blocked on `watchlist`. `None` disables the check.
+    pub fn set_watchlist(env: Env, admin: Address, watchlist: Option<Address>) -> Result<(), CommonError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(CommonError::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(CommonError::NotAuthorized);
+        }
+        admin.require_auth();
+
+        match watchlist {
+            Some(watchlist) => env.storage().instance().set(&DataKey::Watchlist, &watchlist),
+            None => env.storage().instance().remove(&DataKey::Watchlist),
+        }
+        Ok(())
+    }
     
     pub fn list_agent(
         env: Env,
@@ -71,6 +98,12 @@ impl MarketplaceContract {
     pub fn buy_agent(env: Env, buyer: Address, agent_id: u64) -> Result<(), CommonError> {
         buyer.require_auth();
 
+        if let Some(watchlist) = env.storage().instance().get::<_, Address>(&DataKey::Watchlist) {
+            if watchlist::is_blocked_on(&env, &watchlist, &buyer) {
+                return Err(CommonError::NotAuthorized);
+            }
+        }
+
         let key = DataKey::Listing(agent_id);
         let listing: Listing = env.storage().persistent().get(&key).ok_or(CommonError::KeyNotFound)?;
 
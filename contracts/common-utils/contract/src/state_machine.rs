@@ -198,7 +198,10 @@ pub trait StateMachine<T: Clone> {
     }
 }
 
-/// State guard macro - ensures function is only called in specific states
+/// State guard macro - ensures function is only called in specific states.
+///
+/// Entrypoints that do not return `StateError` pass the error to return instead:
+/// `state_guard!(Self, &env, active, ContractError::InvalidState)`.
 #[macro_export]
 macro_rules! state_guard {
     ($contract:ty, $env:expr, active) => {
@@ -210,6 +213,15 @@ macro_rules! state_guard {
     ($contract:ty, $env:expr, not_terminated) => {
         <$contract as StateMachine<_>>::require_not_terminated($env)?
     };
+    ($contract:ty, $env:expr, active, $err:expr) => {
+        <$contract as StateMachine<_>>::require_active($env).map_err(|_| $err)?
+    };
+    ($contract:ty, $env:expr, initialized, $err:expr) => {
+        <$contract as StateMachine<_>>::require_initialized($env).map_err(|_| $err)?
+    };
+    ($contract:ty, $env:expr, not_terminated, $err:expr) => {
+        <$contract as StateMachine<_>>::require_not_terminated($env).map_err(|_| $err)?
+    };
 }
 
 /// Transition guard macro - validates and performs state transition
@@ -518,7 +518,7 @@ impl ScoreStorage {
         let data_bytes = Self::serialize_score_data(&score_data);
         let compressed_data = CompressionManager::compress(&data_bytes, &CompressionType::BitPacking)?;
         
-        let storage_key = Symbol::short(&format!("score_{}", address));
+        let storage_key = Self::score_key(address);
         env.storage().persistent().set(&storage_key, &compressed_data);
         
         // Update score history (compressed)
@@ -527,16 +527,65 @@ impl ScoreStorage {
         Ok(())
     }
     
-    /// Retrieve and decompress score
+    /// Remove a stored score and its history
+    pub fn remove_score(env: &Env, address: &Address) {
+        env.storage().persistent().remove(&Self::score_key(address));
+        env.storage()
+            .persistent()
+            .remove(&Symbol::short(&format!("score_hist_{}", address)));
+    }
+
+    pub fn has_score(env: &Env, address: &Address) -> bool {
+        env.storage().persistent().has(&Self::score_key(address))
+    }
+
+    /// Storage key holding an address's compressed score
+    pub fn score_key(address: &Address) -> Symbol {
+        Symbol::short(&format!("score_{}", address))
+    }
+
+    /// Retrieve and decompress score, with the configured decay applied
     pub fn get_score(env: &Env, address: &Address) -> Result<u32, ContractError> {
-        let storage_key = Symbol::short(&format!("score_{}", address));
+        Ok(Self::get_score_status(env, address)?.score)
+    }
+    
+    /// Retrieve the stored score without applying decay
+    pub fn get_raw_score(env: &Env, address: &Address) -> Result<ScoreData, ContractError> {
+        let storage_key = Self::score_key(address);
         let compressed_data = env.storage().persistent().get(&storage_key)
             .ok_or(ContractError::NotFound)?;
         
         let decompressed_data = CompressionManager::decompress(&compressed_data, &CompressionType::BitPacking)?;
-        let score_data = Self::deserialize_score_data(&decompressed_data)?;
-        
-        Ok(score_data.score)
+        Self::deserialize_score_data(&decompressed_data)
+    }
+    
+    /// Decayed score together with its age and staleness
+    pub fn get_score_status(env: &Env, address: &Address) -> Result<ScoreStatus, ContractError> {
+        let score_data = Self::get_raw_score(env, address)?;
+        let config = Self::get_decay_config(env);
+        let age_seconds = env.ledger().timestamp().saturating_sub(score_data.timestamp);
+        
+        Ok(ScoreStatus {
+            score: config.apply(score_data.score, age_seconds),
+            raw_score: score_data.score,
+            last_updated: score_data.timestamp,
+            age_seconds,
+            is_stale: age_seconds >= config.stale_after_seconds,
+        })
+    }
+    
+    /// Set the decay model applied to scores on read
+    pub fn set_decay_config(env: &Env, config: &DecayConfig) -> Result<(), ContractError> {
+        config.validate()?;
+        env.storage().instance().set(&Symbol::short("decay_cfg"), config);
+        Ok(())
+    }
+    
+    pub fn get_decay_config(env: &Env) -> DecayConfig {
+        env.storage()
+            .instance()
+            .get(&Symbol::short("decay_cfg"))
+            .unwrap_or_default()
     }
     
     /// Get score history
@@ -549,6 +598,103 @@ impl ScoreStorage {
         Self::deserialize_score_history(&decompressed_history, limit)
     }
     
+    /// History entries with `from <= timestamp <= to`, oldest first. `cursor` is the
+    /// number of matching entries already returned by previous pages.
+    pub fn get_score_history_range(
+        env: &Env,
+        address: &Address,
+        from: u64,
+        to: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<ScoreHistoryPage, ContractError> {
+        let history = Self::get_score_history(env, address, u32::MAX)?;
+        let limit = limit.min(MAX_HISTORY_PAGE);
+        
+        let mut entries = Vec::new(env);
+        let mut matched = 0u32;
+        let mut has_more = false;
+        for entry in history.iter() {
+            if entry.timestamp < from || entry.timestamp > to {
+                continue;
+            }
+            if matched >= cursor {
+                if entries.len() == limit {
+                    has_more = true;
+                    break;
+                }
+                entries.push_back(entry);
+            }
+            matched += 1;
+        }
+        
+        Ok(ScoreHistoryPage {
+            next_cursor: if has_more { Some(cursor + limit) } else { None },
+            entries,
+        })
+    }
+    
+    /// Min, max, mean, trend and volatility of the scores recorded in `from..=to`
+    pub fn get_score_history_stats(
+        env: &Env,
+        address: &Address,
+        from: u64,
+        to: u64,
+    ) -> Result<ScoreHistoryStats, ContractError> {
+        let history = Self::get_score_history(env, address, u32::MAX)?;
+        
+        let mut count: i128 = 0;
+        let mut min = u32::MAX;
+        let mut max = 0u32;
+        let mut sum_s: i128 = 0;
+        let mut sum_ss: i128 = 0;
+        let mut sum_t: i128 = 0;
+        let mut sum_tt: i128 = 0;
+        let mut sum_ts: i128 = 0;
+        for entry in history.iter() {
+            if entry.timestamp < from || entry.timestamp > to {
+                continue;
+            }
+            // Times relative to the window start keep the sums small
+            let t = (entry.timestamp - from) as i128;
+            let s = entry.score as i128;
+            count += 1;
+            min = min.min(entry.score);
+            max = max.max(entry.score);
+            sum_s += s;
+            sum_ss += s * s;
+            sum_t += t;
+            sum_tt += t * t;
+            sum_ts += t * s;
+        }
+        
+        if count == 0 {
+            return Err(ContractError::NotFound);
+        }
+        
+        // Least-squares slope, converted from points per second to milli-points per day
+        let denominator = count * sum_tt - sum_t * sum_t;
+        let slope_milli_per_day = if denominator == 0 {
+            0
+        } else {
+            ((count * sum_ts - sum_t * sum_s) * 86_400_000 / denominator) as i64
+        };
+        
+        // Population standard deviation of the scores
+        let variance = (count * sum_ss - sum_s * sum_s) / (count * count);
+        
+        Ok(ScoreHistoryStats {
+            count: count as u32,
+            min,
+            max,
+            mean: (sum_s / count) as u32,
+            slope_milli_per_day,
+            volatility: isqrt(variance.max(0) as u128) as u32,
+            from,
+            to,
+        })
+    }
+    
     fn serialize_score_data(data: &ScoreData) -> Bytes {
         let env = Env::default();
         let mut bytes = Vec::new(&env);
@@ -668,3 +814,140 @@ pub struct ScoreData {
     pub timestamp: u64,
     pub address: Address,
 }
+
+/// Largest page returned by `get_score_history_range`
+pub const MAX_HISTORY_PAGE: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ScoreHistoryPage {
+    pub entries: Vec<ScoreData>,
+    /// Cursor for the next page, `None` when the range is exhausted
+    pub next_cursor: Option<u32>,
+}
+
+/// Statistics over the score history in `from..=to`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreHistoryStats {
+    pub count: u32,
+    pub min: u32,
+    pub max: u32,
+    pub mean: u32,
+    /// Least-squares trend in thousandths of a score point per day
+    pub slope_milli_per_day: i64,
+    /// Standard deviation of the scores, in score points
+    pub volatility: u32,
+    pub from: u64,
+    pub to: u64,
+}
+
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Top of the credit score range; the neutral score must lie within it
+pub const MAX_DECAY_SCORE: u32 = 1000;
+
+/// How a score drifts toward the neutral score as it ages
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecayModel {
+    None,
+    /// Distance to neutral shrinks linearly, reaching neutral after one period
+    Linear,
+    /// Distance to neutral halves every period
+    HalfLife,
+}
+
+/// Score decay configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecayConfig {
+    pub model: DecayModel,
+    pub neutral_score: u32,
+    pub period_seconds: u64,       // linear decay length or half-life
+    pub grace_period_seconds: u64, // no decay before this age
+    pub stale_after_seconds: u64,  // age at which a re-evaluation is due
+}
+
+impl Default for DecayConfig {
+    fn default() -> Self {
+        Self {
+            model: DecayModel::None,
+            neutral_score: 500,
+            period_seconds: 31_536_000,      // 1 year
+            grace_period_seconds: 2_592_000, // 30 days
+            stale_after_seconds: 15_552_000, // 180 days
+        }
+    }
+}
+
+impl DecayConfig {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.model != DecayModel::None && self.period_seconds == 0 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        if self.stale_after_seconds == 0 || self.neutral_score > MAX_DECAY_SCORE {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+    
+    /// Apply decay to a score of the given age
+    pub fn apply(&self, score: u32, age_seconds: u64) -> u32 {
+        if age_seconds <= self.grace_period_seconds || score == self.neutral_score {
+            return score;
+        }
+        let elapsed = age_seconds - self.grace_period_seconds;
+        let distance = score.abs_diff(self.neutral_score) as u64;
+        
+        let remaining = match self.model {
+            DecayModel::None => distance,
+            DecayModel::Linear => {
+                if elapsed >= self.period_seconds {
+                    0
+                } else {
+                    distance * (self.period_seconds - elapsed) / self.period_seconds
+                }
+            }
+            DecayModel::HalfLife => {
+                let halvings = elapsed / self.period_seconds;
+                if halvings >= 32 {
+                    0
+                } else {
+                    // Whole half-lives by shifting, the partial one interpolated
+                    let at_step = distance >> halvings;
+                    let partial = elapsed % self.period_seconds;
+                    at_step - (at_step - at_step / 2) * partial / self.period_seconds
+                }
+            }
+        } as u32;
+        
+        if score > self.neutral_score {
+            self.neutral_score + remaining
+        } else {
+            self.neutral_score - remaining
+        }
+    }
+}
+
+/// Score as seen by readers after decay
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreStatus {
+    pub score: u32,
+    pub raw_score: u32,
+    pub last_updated: u64,
+    pub age_seconds: u64,
+    pub is_stale: bool,
+}
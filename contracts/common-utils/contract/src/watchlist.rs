@@ -0,0 +1,200 @@
+//! # Watchlist
+//!
+//! Shared blocklist of addresses flagged by reviewers. Each entry records a
+//! reason, a severity and an optional expiry; an entry stops counting once
+//! it has expired, without anyone having to remove it.
+//!
+//! Other contracts call [`WatchlistContract::is_blocked`] (or the
+//! [`is_blocked_on`] helper) before acting for a caller. The check reads the
+//! address's entry and the configured block severity (default `High`); only
+//! entries at or above that severity block, lower ones are informational.
+
+use crate::error::CommonError;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, IntoVal, Symbol,
+};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum WatchSeverity {
+    Low = 1,
+    Medium = 2,
+    High = 3,
+    Critical = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WatchlistEntry {
+    pub address: Address,
+    pub reason: Symbol,
+    pub severity: WatchSeverity,
+    pub listed_by: Address,
+    pub listed_at: u64,
+    /// Entry is ignored from this timestamp on; `None` never expires
+    pub expires_at: Option<u64>,
+}
+
+impl WatchlistEntry {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    AclContract,
+    BlockSeverity,
+    Entry(Address),
+}
+
+#[contract]
+pub struct WatchlistContract;
+
+#[contractimpl]
+impl WatchlistContract {
+    /// Reviewers need the `("watch", "manage")` permission on `acl_contract`
+    pub fn initialize(env: Env, admin: Address, acl_contract: Address) -> Result<(), CommonError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(CommonError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::AclContract, &acl_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::BlockSeverity, &WatchSeverity::High);
+        Ok(())
+    }
+
+    /// Lowest severity at which an entry blocks its address
+    pub fn set_block_severity(
+        env: Env,
+        admin: Address,
+        severity: WatchSeverity,
+    ) -> Result<(), CommonError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::BlockSeverity, &severity);
+        Ok(())
+    }
+
+    pub fn get_block_severity(env: Env) -> WatchSeverity {
+        env.storage()
+            .instance()
+            .get(&DataKey::BlockSeverity)
+            .unwrap_or(WatchSeverity::High)
+    }
+
+    /// Add or replace the entry for `address`
+    pub fn add_entry(
+        env: Env,
+        reviewer: Address,
+        address: Address,
+        reason: Symbol,
+        severity: WatchSeverity,
+        expires_at: Option<u64>,
+    ) -> Result<(), CommonError> {
+        Self::require_reviewer(&env, &reviewer)?;
+
+        let now = env.ledger().timestamp();
+        if expires_at.map_or(false, |expires_at| expires_at <= now) {
+            return Err(CommonError::OutOfRange);
+        }
+
+        let entry = WatchlistEntry {
+            address: address.clone(),
+            reason: reason.clone(),
+            severity,
+            listed_by: reviewer,
+            listed_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Entry(address.clone()), &entry);
+
+        env.events().publish(
+            (symbol_short!("wl_add"), address),
+            (reason, severity, expires_at),
+        );
+        Ok(())
+    }
+
+    /// Remove the entry for `address`; `reason` is recorded in the removal event
+    pub fn remove_entry(
+        env: Env,
+        reviewer: Address,
+        address: Address,
+        reason: Symbol,
+    ) -> Result<(), CommonError> {
+        Self::require_reviewer(&env, &reviewer)?;
+
+        let key = DataKey::Entry(address.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(CommonError::KeyNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("wl_rem"), address), (reviewer, reason));
+        Ok(())
+    }
+
+    /// Entry for `address`, including expired ones
+    pub fn get_entry(env: Env, address: Address) -> Option<WatchlistEntry> {
+        env.storage().persistent().get(&DataKey::Entry(address))
+    }
+
+    /// True while `address` has an unexpired entry at or above the block severity
+    pub fn is_blocked(env: Env, address: Address) -> bool {
+        let entry: WatchlistEntry = match env.storage().persistent().get(&DataKey::Entry(address)) {
+            Some(entry) => entry,
+            None => return false,
+        };
+        entry.is_active(env.ledger().timestamp())
+            && entry.severity >= Self::get_block_severity(env.clone())
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), CommonError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(CommonError::NotInitialized)?;
+        if stored_admin != *admin {
+            return Err(CommonError::NotAuthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn require_reviewer(env: &Env, reviewer: &Address) -> Result<(), CommonError> {
+        reviewer.require_auth();
+        let acl: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AclContract)
+            .ok_or(CommonError::NotInitialized)?;
+        if !crate::check_permission(
+            env.clone(),
+            acl,
+            reviewer.clone(),
+            symbol_short!("watch"),
+            symbol_short!("manage"),
+        ) {
+            return Err(CommonError::NotAuthorized);
+        }
+        Ok(())
+    }
+}
+
+/// Ask the watchlist at `watchlist` whether `address` is blocked
+pub fn is_blocked_on(env: &Env, watchlist: &Address, address: &Address) -> bool {
+    env.invoke_contract::<bool>(
+        watchlist,
+        &Symbol::new(env, "is_blocked"),
+        soroban_sdk::vec![env, address.into_val(env)],
+    )
+}
@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use super::error::CommonError;
+use super::marketplace::{MarketplaceContract, MarketplaceContractClient};
+use super::watchlist::{WatchSeverity, WatchlistContract, WatchlistContractClient};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+#[contract]
+pub struct MockAcl;
+
+#[contractimpl]
+impl MockAcl {
+    pub fn grant(env: Env, user: Address) {
+        env.storage().instance().set(&user, &true);
+    }
+
+    pub fn has_permission(env: Env, user: Address, _resource: Symbol, _action: Symbol) -> bool {
+        env.storage().instance().get(&user).unwrap_or(false)
+    }
+}
+
+fn setup_test(env: &Env) -> (WatchlistContractClient<'static>, Address, Address) {
+    let admin = Address::generate(env);
+    let reviewer = Address::generate(env);
+
+    let acl_id = env.register_contract(None, MockAcl);
+    MockAclClient::new(env, &acl_id).grant(&reviewer);
+
+    let watchlist_id = env.register_contract(None, WatchlistContract);
+    let client = WatchlistContractClient::new(env, &watchlist_id);
+    client.initialize(&admin, &acl_id);
+
+    (client, admin, reviewer)
+}
+
+#[test]
+fn test_add_and_remove_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, reviewer) = setup_test(&env);
+    let address = Address::generate(&env);
+
+    assert!(!client.is_blocked(&address));
+
+    client.add_entry(
+        &reviewer,
+        &address,
+        &Symbol::new(&env, "sanctions"),
+        &WatchSeverity::Critical,
+        &None,
+    );
+    assert!(client.is_blocked(&address));
+    let entry = client.get_entry(&address).unwrap();
+    assert_eq!(entry.listed_by, reviewer);
+    assert_eq!(entry.severity, WatchSeverity::Critical);
+
+    let reason = Symbol::new(&env, "resolved");
+    client.remove_entry(&reviewer, &address, &reason);
+    assert!(!client.is_blocked(&address));
+    assert_eq!(
+        client.try_remove_entry(&reviewer, &address, &reason),
+        Err(Ok(CommonError::KeyNotFound))
+    );
+}
+
+#[test]
+fn test_only_reviewers_manage_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _) = setup_test(&env);
+    let outsider = Address::generate(&env);
+    let address = Address::generate(&env);
+
+    let result = client.try_add_entry(
+        &outsider,
+        &address,
+        &Symbol::new(&env, "spam"),
+        &WatchSeverity::High,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(CommonError::NotAuthorized)));
+}
+
+#[test]
+fn test_entries_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _, reviewer) = setup_test(&env);
+    let address = Address::generate(&env);
+    let reason = Symbol::new(&env, "phishing");
+
+    assert_eq!(
+        client.try_add_entry(&reviewer, &address, &reason, &WatchSeverity::High, &Some(1000)),
+        Err(Ok(CommonError::OutOfRange))
+    );
+
+    client.add_entry(&reviewer, &address, &reason, &WatchSeverity::High, &Some(2000));
+    assert!(client.is_blocked(&address));
+
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    assert!(!client.is_blocked(&address));
+    assert!(client.get_entry(&address).is_some());
+}
+
+#[test]
+fn test_block_severity_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, reviewer) = setup_test(&env);
+    let address = Address::generate(&env);
+
+    client.add_entry(
+        &reviewer,
+        &address,
+        &Symbol::new(&env, "review"),
+        &WatchSeverity::Medium,
+        &None,
+    );
+    assert!(!client.is_blocked(&address));
+
+    client.set_block_severity(&admin, &WatchSeverity::Medium);
+    assert!(client.is_blocked(&address));
+}
+
+#[test]
+fn test_marketplace_rejects_blocked_buyer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, reviewer) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let marketplace_admin = Address::generate(&env);
+
+    let marketplace_id = env.register(MarketplaceContract, (&marketplace_admin,));
+    let marketplace = MarketplaceContractClient::new(&env, &marketplace_id);
+    assert_eq!(
+        marketplace.try_set_watchlist(&buyer, &Some(buyer.clone())),
+        Err(Ok(CommonError::NotAuthorized))
+    );
+    marketplace.set_watchlist(&marketplace_admin, &Some(client.address.clone()));
+
+    client.add_entry(
+        &reviewer,
+        &buyer,
+        &Symbol::new(&env, "fraud"),
+        &WatchSeverity::High,
+        &None,
+    );
+
+    assert_eq!(
+        marketplace.try_buy_agent(&buyer, &1),
+        Err(Ok(CommonError::NotAuthorized))
+    );
+}
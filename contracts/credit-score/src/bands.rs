@@ -0,0 +1,121 @@
+//! # Score Bands
+//!
+//! Named score ranges ("poor", "fair", ...) shared by every frontend. Bands
+//! are configured by the admin as an ascending list of lower bounds; each band
+//! runs up to the next band's lower bound, and the last band up to
+//! [`MAX_SCORE`].
+//!
+//! Account counts are kept per [`BAND_GRANULARITY`]-point bucket rather than
+//! per band, so replacing the bands never has to revisit every account: the
+//! new bands' counts are summed from the buckets on read. Band bounds must
+//! therefore be multiples of the granularity.
+
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::scoring::MAX_SCORE;
+
+/// Upper limit on the number of configured bands
+pub const MAX_BANDS: u32 = 10;
+/// Width of a score bucket; band bounds must be multiples of it
+pub const BAND_GRANULARITY: u32 = 10;
+const BUCKET_COUNT: u32 = MAX_SCORE / BAND_GRANULARITY + 1;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBand {
+    pub name: Symbol,
+    /// Lowest score (inclusive) that falls into this band
+    pub min_score: u32,
+}
+
+/// Number of accounts whose latest stored score falls in `band`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandCount {
+    pub band: ScoreBand,
+    pub accounts: u32,
+}
+
+pub fn default_bands(env: &Env) -> Vec<ScoreBand> {
+    let mut bands = Vec::new(env);
+    bands.push_back(ScoreBand {
+        name: symbol_short!("poor"),
+        min_score: 0,
+    });
+    bands.push_back(ScoreBand {
+        name: symbol_short!("fair"),
+        min_score: 580,
+    });
+    bands.push_back(ScoreBand {
+        name: symbol_short!("good"),
+        min_score: 670,
+    });
+    bands.push_back(ScoreBand {
+        name: symbol_short!("excellent"),
+        min_score: 800,
+    });
+    bands
+}
+
+/// Bands must start at 0, be strictly ascending, fall on bucket boundaries
+/// and stay within the score range
+pub fn validate_bands(bands: &Vec<ScoreBand>) -> Result<(), ContractError> {
+    if bands.is_empty() || bands.len() > MAX_BANDS {
+        return Err(ContractError::InvalidConfiguration);
+    }
+    if bands.get(0).unwrap().min_score != 0 {
+        return Err(ContractError::InvalidConfiguration);
+    }
+
+    let mut previous: Option<u32> = None;
+    for band in bands.iter() {
+        if band.min_score > MAX_SCORE || band.min_score % BAND_GRANULARITY != 0 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        if let Some(prev) = previous {
+            if band.min_score <= prev {
+                return Err(ContractError::InvalidConfiguration);
+            }
+        }
+        previous = Some(band.min_score);
+    }
+    Ok(())
+}
+
+/// Index of the band containing `score`. `bands` must be valid.
+pub fn band_index(bands: &Vec<ScoreBand>, score: u32) -> u32 {
+    let mut index = 0;
+    for (i, band) in bands.iter().enumerate() {
+        if score >= band.min_score {
+            index = i as u32;
+        }
+    }
+    index
+}
+
+/// Bucket holding `score`
+pub fn bucket(score: u32) -> u32 {
+    score.min(MAX_SCORE) / BAND_GRANULARITY
+}
+
+pub fn empty_histogram(env: &Env) -> Vec<u32> {
+    let mut histogram = Vec::new(env);
+    for _ in 0..BUCKET_COUNT {
+        histogram.push_back(0u32);
+    }
+    histogram
+}
+
+/// Accounts per band, summed from the per-bucket `histogram`
+pub fn counts_from_histogram(env: &Env, bands: &Vec<ScoreBand>, histogram: &Vec<u32>) -> Vec<u32> {
+    let mut counts = Vec::new(env);
+    for _ in bands.iter() {
+        counts.push_back(0u32);
+    }
+    for (index, accounts) in histogram.iter().enumerate() {
+        let band = band_index(bands, index as u32 * BAND_GRANULARITY);
+        counts.set(band, counts.get(band).unwrap() + accounts);
+    }
+    counts
+}
@@ -0,0 +1,66 @@
+//! # Bulk Score Import
+//!
+//! Admin-only onboarding of large score sets without going through the
+//! rate-limited `set_score`. An import job is opened with the number of
+//! chunks to expect and a [`RollbackStrategy`]; chunks must then be submitted
+//! in order, and a chunk index that has already been applied is rejected, so
+//! a failed submission can simply be retried with the same index.
+//!
+//! Every row is validated before anything is written:
+//!
+//! - `AllOrNothing` - one invalid row rejects the whole chunk. Nothing is
+//!   written and the job does not advance, so the fixed chunk can be resent.
+//! - `Partial` - valid rows are written, invalid rows are reported as failed.
+
+use common_utils::batch::{OperationResult, RollbackStrategy};
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Row error codes reported in `OperationResult::error_code`
+pub const ROW_SCORE_OUT_OF_RANGE: u32 = 1;
+pub const ROW_TIMESTAMP_INVALID: u32 = 2;
+pub const ROW_SCORE_FROZEN: u32 = 3;
+pub const ROW_DUPLICATE_ACCOUNT: u32 = 4;
+/// The stored score is newer than the imported one (row is skipped, not failed)
+pub const ROW_STALE: u32 = 5;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreImportRow {
+    pub account: Address,
+    pub score: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportJob {
+    pub id: u64,
+    pub strategy: RollbackStrategy,
+    pub total_chunks: u32,
+    /// Index of the next chunk the job accepts
+    pub next_chunk: u32,
+    pub imported: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    pub created_at: u64,
+    pub completed_at: Option<u64>,
+}
+
+impl ImportJob {
+    pub fn is_complete(&self) -> bool {
+        self.next_chunk >= self.total_chunks
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportChunkResult {
+    pub job_id: u64,
+    pub chunk_index: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    /// True when an `AllOrNothing` chunk was rejected and must be resent
+    pub rolled_back: bool,
+    pub results: Vec<OperationResult>,
+}
@@ -0,0 +1,35 @@
+//! # Score Access Consent
+//!
+//! Score reads are limited to the account owner, the contract admin, and
+//! lenders the owner has granted consent to. Grants carry a purpose and an
+//! expiry; every read by someone other than the owner is appended to the
+//! owner's access log.
+
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Maximum access log entries kept per account (oldest are dropped first)
+pub const MAX_ACCESS_LOG: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsentGrant {
+    pub lender: Address,
+    pub purpose: Symbol,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+impl ConsentGrant {
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessLogEntry {
+    pub accessor: Address,
+    pub function: Symbol,
+    pub purpose: Option<Symbol>,
+    pub timestamp: u64,
+}
@@ -0,0 +1,50 @@
+//! # Score Disputes
+//!
+//! An account holder can contest their stored score by opening a dispute with
+//! an evidence CID. While a dispute is open the account's score is frozen:
+//! `set_score` and `calculate_score` reject writes until a reviewer holding the
+//! ACL `("score", "resolve")` permission (granted to `Role::Reviewer`) either
+//! corrects the score or rejects the dispute.
+
+use soroban_sdk::{contracttype, Address, Bytes};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Corrected,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dispute {
+    pub id: u64,
+    pub account: Address,
+    pub disputed_score: u32,
+    pub evidence_cid: Bytes,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    pub reviewer: Option<Address>,
+    pub corrected_score: Option<u32>,
+    pub resolved_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeAction {
+    Opened,
+    Corrected,
+    Rejected,
+}
+
+/// One entry in a dispute's audit trail
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeAuditEntry {
+    pub action: DisputeAction,
+    pub actor: Address,
+    pub score_before: u32,
+    pub score_after: u32,
+    pub timestamp: u64,
+}
@@ -0,0 +1,123 @@
+//! # Scored Account Index
+//!
+//! Enumerable set of every account with a stored score. Entries live in
+//! persistent storage as a dense array (`ScoredAccount(i)`) plus a reverse
+//! lookup (`ScoredAccountPos(account)`), so inserts, removals and page reads
+//! are all constant work per account.
+//!
+//! Removal swaps the last entry into the freed slot. A paginated walk that
+//! runs while accounts are being removed may therefore skip or repeat an
+//! account; callers that need an exact pass should pause writes first.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::DataKey;
+
+/// Largest page returned by a single index read
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoredAccountPage {
+    pub accounts: Vec<Address>,
+    /// Cursor for the next page, `None` once the end of the index is reached
+    pub next_cursor: Option<u32>,
+    pub total: u32,
+}
+
+pub struct ScoredAccountIndex;
+
+impl ScoredAccountIndex {
+    pub fn len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoredAccountCount)
+            .unwrap_or(0)
+    }
+
+    pub fn contains(env: &Env, account: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::ScoredAccountPos(account.clone()))
+    }
+
+    /// Add `account` if it is not indexed yet. Returns whether it was added.
+    pub fn insert(env: &Env, account: &Address) -> bool {
+        if Self::contains(env, account) {
+            return false;
+        }
+
+        let position = Self::len(env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoredAccount(position), account);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoredAccountPos(account.clone()), &position);
+        env.storage()
+            .instance()
+            .set(&DataKey::ScoredAccountCount, &(position + 1));
+        true
+    }
+
+    /// Remove `account` if indexed. Returns whether it was removed.
+    pub fn remove(env: &Env, account: &Address) -> bool {
+        let position: u32 = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoredAccountPos(account.clone()))
+        {
+            Some(position) => position,
+            None => return false,
+        };
+
+        let last = Self::len(env) - 1;
+        if position != last {
+            let moved: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ScoredAccount(last))
+                .unwrap();
+            env.storage()
+                .persistent()
+                .set(&DataKey::ScoredAccount(position), &moved);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ScoredAccountPos(moved), &position);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoredAccount(last));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoredAccountPos(account.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::ScoredAccountCount, &last);
+        true
+    }
+
+    /// Up to `limit` accounts (capped at [`MAX_PAGE_SIZE`]) starting at `cursor`
+    pub fn page(env: &Env, cursor: u32, limit: u32) -> ScoredAccountPage {
+        let total = Self::len(env);
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(total);
+
+        let mut accounts = Vec::new(env);
+        for position in cursor..end {
+            if let Some(account) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ScoredAccount(position))
+            {
+                accounts.push_back(account);
+            }
+        }
+
+        ScoredAccountPage {
+            accounts,
+            next_cursor: if end < total { Some(end) } else { None },
+            total,
+        }
+    }
+}
@@ -1,19 +1,43 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec, Bytes};
-use common_utils::error::{AuthorizationError, StateError, ValidationError, ContractError};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec, Bytes, BytesN};
+use soroban_sdk::xdr::ToXdr;
+use common_utils::error::{AuthorizationError, StateError, ContractError};
 use common_utils::{rate_limit, rate_limit_adaptive};
 use common_utils::rate_limit::{RateLimiter, TrustTier};
-use common_utils::storage_optimization::{ScoreStorage, DataSeparator, DataTemperature};
+use common_utils::storage_optimization::{ScoreStorage, DataSeparator, DataTemperature, DecayConfig, ScoreStatus, ScoreHistoryPage, ScoreHistoryStats};
 use common_utils::storage_monitoring::{StorageTracker, PerformanceMonitor};
 use common_utils::data_migration::{DataMigrationManager, MigrationConfig, CompressionType};
 use common_utils::compression::{CompressionManager, CompressionType};
 use common_utils::dex::{DexAdapter, StellarDexAdapter, TokenPair, DexConfig};
 use common_utils::dex::trading_data::{TradingData, TradingVolume, PriceData};
 use common_utils::dex::liquidity::{LiquidityMetrics, PoolInfo};
-use common_utils::dex::scoring_signals::{SignalAggregator, ScoringSignal, SignalType, SignalWeight};
+use common_utils::dex::scoring_signals::{SignalAggregator, ScoringSignal, SignalType, SignalWeight, SignalWeightSet, SignalWeightStore, PairContribution, MultiPairScore};
 use common_utils::dex::cache::{DexDataCache, CacheConfig};
+use common_utils::dex::account_activity::{AccountTrade, AccountTradingLedger};
 use common_utils::state_machine::{State, StateMachine, CreditScoreState};
 use common_utils::{state_guard, transition_to};
+use common_utils::validator::{CIDValidator, Validator};
+use common_utils::batch::{OperationResult, OperationStatus, RollbackStrategy, MAX_BATCH_SIZE};
+use common_utils::watchlist;
+
+mod bands;
+mod bulk_import;
+mod consent;
+mod dispute;
+mod index;
+mod oracle;
+mod penalties;
+mod reasons;
+mod scoring;
+pub use bands::{BandCount, ScoreBand, MAX_BANDS};
+pub use bulk_import::{ImportChunkResult, ImportJob, ScoreImportRow};
+pub use consent::{AccessLogEntry, ConsentGrant, MAX_ACCESS_LOG};
+pub use dispute::{Dispute, DisputeAction, DisputeAuditEntry, DisputeStatus};
+pub use index::{ScoredAccountIndex, ScoredAccountPage, MAX_PAGE_SIZE};
+pub use oracle::{OracleKey, ScoreAttestation, ScoreProvenance};
+pub use penalties::{AppliedPenalties, PenaltyConfig, RiskLevel};
+pub use reasons::{ReasonCode, ReasonEngine, ScoreReason, DEFAULT_REASON_COUNT};
+pub use scoring::{CreditFactors, ScoreBreakdown, ScoringConfig, ScoringEngine, MAX_SCORE};
 
 #[contracttype]
 pub enum DataKey {
@@ -27,6 +51,36 @@ pub enum DataKey {
     ScoreSignals(Address),
     DexEnabled,
     ContractState,
+    ScoringConfig,
+    ScoreBreakdown(Address),
+    AclContract,
+    DisputeCount,
+    Dispute(u64),
+    DisputeAudit(u64),
+    OpenDispute(Address),
+    AccountDisputes(Address),
+    Consent(Address, Address),
+    ConsentLenders(Address),
+    AccessLog(Address),
+    Indexer(Address),
+    AccountLedger(Address),
+    ScoreSignalsVersion(Address),
+    ScoreBands,
+    ScoreHistogram,
+    ScoredAccountCount,
+    ScoredAccount(u32),
+    ScoredAccountPos(Address),
+    FraudContract,
+    RiskContract,
+    PenaltyConfig,
+    FraudAgentId(Address),
+    OracleKey(BytesN<32>),
+    OracleNonce(BytesN<32>, u64),
+    ScoreProvenance(Address),
+    ImportCount,
+    ImportJob(u64),
+    Watchlist,
+    ScoreFreeze(Address),
 }
 
 #[contract]
@@ -72,7 +126,44 @@ impl CreditScoreContract {
         Ok(())
     }
 
+    /// Stop all mutating entrypoints. Reads keep working.
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+
+        let state = Self::get_state(&env);
+        let state_data = state.get_data().ok_or(ContractError::NotInitialized)?;
+        Self::transition(&env, State::Paused(state_data.clone()))
+            .map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    pub fn resume(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        let state = Self::get_state(&env);
+        if !state.is_paused() {
+            return Err(ContractError::InvalidState);
+        }
+
+        let state_data = state.get_data().ok_or(ContractError::NotInitialized)?;
+        Self::transition(&env, State::Active(state_data.clone()))
+            .map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    /// Permanently disable the contract. Stored scores stay readable.
+    pub fn terminate(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        Self::transition(&env, State::Terminated).map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    pub fn get_contract_state(env: Env) -> State<CreditScoreState> {
+        Self::get_state(&env)
+    }
+
     pub fn initialize_dex(env: Env, admin: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
         
         StellarDexAdapter::initialize(&env)
@@ -84,6 +175,7 @@ impl CreditScoreContract {
     }
 
     pub fn add_supported_pair(env: Env, admin: Address, pair: TokenPair) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
         
         let mut adapter = StellarDexAdapter::new(&env);
@@ -100,6 +192,7 @@ impl CreditScoreContract {
     }
 
     pub fn set_dex_config(env: Env, admin: Address, config: DexConfig) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
         config.validate().map_err(|_| ContractError::InvalidConfiguration)?;
         env.storage().instance().set(&DataKey::DexConfig, &config);
@@ -118,6 +211,7 @@ impl CreditScoreContract {
         user: Address,
         tier: TrustTier,
     ) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -136,6 +230,7 @@ impl CreditScoreContract {
         admin: Address,
         load: u32,
     ) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -149,11 +244,16 @@ impl CreditScoreContract {
         Ok(())
     }
 
+    /// Adjust the base score with DEX signals. `pair` supplies the pool-level
+    /// signals; when the account has a trading ledger, volume, frequency and
+    /// slippage come from it instead, and those cover all of the account's
+    /// pairs rather than `pair` alone.
     pub fn calculate_score_with_dex(
         env: Env,
         account_id: Address,
         pair: TokenPair,
     ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "calc_dex_score"));
         
         let base_score = Self::get_base_score(&env, &account_id)?;
@@ -169,12 +269,24 @@ impl CreditScoreContract {
         let trading_data = Self::fetch_trading_data(&env, &pair)?;
         let liquidity = Self::fetch_liquidity_metrics(&env, &pair)?;
         
-        let aggregator = SignalAggregator::new(&env);
-        let signals = aggregator.aggregate(&trading_data, &liquidity);
+        let aggregator = SignalAggregator::load(&env);
+        let ledger: Option<AccountTradingLedger> = env.storage().persistent()
+            .get(&DataKey::AccountLedger(account_id.clone()));
+        let signals = match ledger {
+            Some(ledger) => aggregator.aggregate_with_account(&trading_data, &liquidity, &ledger),
+            None => aggregator.aggregate(&trading_data, &liquidity),
+        };
         
         let adjusted_score = aggregator.calculate_credit_impact(base_score, &signals);
         
         env.storage().instance().set(&DataKey::ScoreSignals(account_id.clone()), &signals);
+        env.storage().instance().set(&DataKey::ScoreSignalsVersion(account_id.clone()), &aggregator.version());
+        
+        let reason_codes = Self::reason_engine(&env, &account_id).top_codes(DEFAULT_REASON_COUNT);
+        env.events().publish(
+            (symbol_short!("score_dex"), account_id),
+            (adjusted_score as u32, reason_codes),
+        );
         
         StorageTracker::record_operation(
             &env,
@@ -189,21 +301,160 @@ impl CreditScoreContract {
         Ok(adjusted_score as u32)
     }
 
+    /// Compute an account's score from its stored factors and persist it
     pub fn calculate_score(
         env: Env,
-        account_id: String,
-    ) -> Result<u32, ValidationError> {
-        if account_id.is_empty() {
-            return Err(ValidationError::MissingRequiredField);
+        account_id: Address,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "calc_score"));
+        Self::require_not_frozen(&env, &account_id)?;
+
+        let compressed: Bytes = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Factors(account_id.clone()))
+            .ok_or(ContractError::NotFound)?;
+
+        let config = Self::get_scoring_config(env.clone());
+        let engine = ScoringEngine::new(&env, config.clone());
+        let factors = engine.decode_factors(&compressed)?;
+        let mut breakdown = engine.score(&factors);
+
+        let penalties = penalties::collect(
+            &env,
+            &Self::get_penalty_config(env.clone()),
+            &account_id,
+            env.storage().instance().get(&DataKey::FraudContract),
+            env.storage().persistent().get(&DataKey::FraudAgentId(account_id.clone())),
+            env.storage().instance().get(&DataKey::RiskContract),
+        );
+        breakdown.apply_penalties(penalties, config.min_score);
+
+        Self::write_score(&env, &account_id, breakdown.score, env.ledger().timestamp())?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoreBreakdown(account_id.clone()), &breakdown);
+
+        StorageTracker::record_operation(
+            &env,
+            &Symbol::new(&env, "store"),
+            &Symbol::new(&env, "score"),
+            44,
+            true,
+        );
+
+        let reason_codes = Self::reason_engine(&env, &account_id).top_codes(DEFAULT_REASON_COUNT);
+        env.events().publish(
+            (Symbol::new(&env, "score_calc"), account_id),
+            (breakdown.score, breakdown.computed_at, reason_codes),
+        );
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "calc_score"));
+
+        Ok(breakdown.score)
+    }
+
+    pub fn get_score_breakdown(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+    ) -> Result<Option<ScoreBreakdown>, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_breakdown")?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoreBreakdown(account_id)))
+    }
+
+    pub fn set_scoring_config(
+        env: Env,
+        admin: Address,
+        config: ScoringConfig,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        config.validate()?;
+        env.storage().instance().set(&DataKey::ScoringConfig, &config);
+        Ok(())
+    }
+
+    pub fn get_scoring_config(env: Env) -> ScoringConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoringConfig)
+            .unwrap_or_default()
+    }
+
+    /// Point `calculate_score` at the fraud-detect and risk-eval contracts. `None` disables a source.
+    pub fn set_risk_contracts(
+        env: Env,
+        admin: Address,
+        fraud_contract: Option<Address>,
+        risk_contract: Option<Address>,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        match fraud_contract {
+            Some(contract) => env.storage().instance().set(&DataKey::FraudContract, &contract),
+            None => env.storage().instance().remove(&DataKey::FraudContract),
         }
-        Ok(500)
+        match risk_contract {
+            Some(contract) => env.storage().instance().set(&DataKey::RiskContract, &contract),
+            None => env.storage().instance().remove(&DataKey::RiskContract),
+        }
+        Ok(())
+    }
+
+    pub fn get_risk_contracts(env: Env) -> (Option<Address>, Option<Address>) {
+        (
+            env.storage().instance().get(&DataKey::FraudContract),
+            env.storage().instance().get(&DataKey::RiskContract),
+        )
+    }
+
+    pub fn set_penalty_config(
+        env: Env,
+        admin: Address,
+        config: PenaltyConfig,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        config.validate()?;
+        env.storage().instance().set(&DataKey::PenaltyConfig, &config);
+        Ok(())
+    }
+
+    pub fn get_penalty_config(env: Env) -> PenaltyConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::PenaltyConfig)
+            .unwrap_or_default()
+    }
+
+    /// Link an account to its fraud-detect agent id so fraud reports count against its score
+    pub fn link_fraud_agent(
+        env: Env,
+        admin: Address,
+        account_id: Address,
+        agent_id: Symbol,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::FraudAgentId(account_id), &agent_id);
+        Ok(())
     }
 
-    pub fn get_score(env: Env, account_id: Address) -> Result<u32, AuthorizationError> {
-        rate_limit_adaptive!(env, account_id, "get_score",
+    pub fn get_score(env: Env, caller: Address, account_id: Address) -> Result<u32, AuthorizationError> {
+        rate_limit_adaptive!(env, caller, "get_score",
             max: 60, window: 3600,
             strategy: TokenBucket, scope: PerUser);
 
+        Self::require_read_access(&env, &caller, &account_id, "get_score")
+            .map_err(|_| AuthorizationError::NotAuthorized)?;
+
         let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "get_score"));
         
         let result = ScoreStorage::get_score(&env, &account_id)
@@ -222,32 +473,192 @@ impl CreditScoreContract {
         Ok(result)
     }
 
-    pub fn get_score_with_signals(env: Env, account_id: Address) -> Result<ScoreWithSignals, ContractError> {
+    pub fn get_score_with_signals(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+    ) -> Result<ScoreWithSignals, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_signals")?;
         let base_score = Self::get_base_score(&env, &account_id)?;
         
         let signals: Vec<ScoringSignal> = env.storage().instance()
             .get(&DataKey::ScoreSignals(account_id.clone()))
             .unwrap_or_else(|| Vec::new(&env));
         
-        let aggregator = SignalAggregator::new(&env);
+        let aggregator = SignalAggregator::load(&env);
         let dex_adjustment = if !signals.is_empty() {
             aggregator.calculate_score(&signals) as i32 - 50
         } else {
             0
         };
         
+        let is_stale = ScoreStorage::get_score_status(&env, &account_id)
+            .map(|status| status.is_stale)
+            .unwrap_or(true);
+        
+        let weights_version: u32 = env.storage().instance()
+            .get(&DataKey::ScoreSignalsVersion(account_id.clone()))
+            .unwrap_or(0);
+        
+        let reasons = Self::reason_engine(&env, &account_id).top(DEFAULT_REASON_COUNT);
+        
         Ok(ScoreWithSignals {
             base_score,
             dex_adjustment,
             signals,
+            is_stale,
+            weights_version,
+            reasons,
         })
     }
 
+    /// The `limit` factors and signals costing the account the most score points
+    pub fn get_score_reasons(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+        limit: u32,
+    ) -> Result<Vec<ScoreReason>, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_reasons")?;
+        Ok(Self::reason_engine(&env, &account_id).top(limit))
+    }
+
+    /// Decayed score, raw score and staleness for an account
+    pub fn get_score_status(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+    ) -> Result<ScoreStatus, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_status")?;
+        ScoreStorage::get_score_status(&env, &account_id)
+    }
+
+    /// Allow `lender` to read the owner's score until `expires_at`
+    pub fn grant_consent(
+        env: Env,
+        owner: Address,
+        lender: Address,
+        purpose: Symbol,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        owner.require_auth();
+        Self::require_not_blocked(&env, &owner)?;
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now || lender == owner {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let grant = ConsentGrant {
+            lender: lender.clone(),
+            purpose: purpose.clone(),
+            granted_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Consent(owner.clone(), lender.clone()), &grant);
+
+        let mut lenders: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ConsentLenders(owner.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        if !lenders.contains(&lender) {
+            lenders.push_back(lender.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::ConsentLenders(owner.clone()), &lenders);
+        }
+
+        env.events().publish(
+            (symbol_short!("cnst_grt"), owner),
+            (lender, purpose, expires_at),
+        );
+
+        Ok(())
+    }
+
+    pub fn revoke_consent(env: Env, owner: Address, lender: Address) -> Result<(), ContractError> {
+        // Withdrawing consent only narrows access, so owners may still do it while paused
+        state_guard!(Self, &env, not_terminated, ContractError::InvalidState);
+        owner.require_auth();
+
+        let key = DataKey::Consent(owner.clone(), lender.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::NotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        let lenders: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ConsentLenders(owner.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        if let Some(index) = lenders.first_index_of(&lender) {
+            let mut remaining = lenders;
+            remaining.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ConsentLenders(owner.clone()), &remaining);
+        }
+
+        env.events().publish((symbol_short!("cnst_rvk"), owner), lender);
+
+        Ok(())
+    }
+
+    pub fn get_consent(env: Env, owner: Address, lender: Address) -> Option<ConsentGrant> {
+        env.storage().persistent().get(&DataKey::Consent(owner, lender))
+    }
+
+    /// All grants the owner has issued, including expired ones
+    pub fn list_consents(env: Env, owner: Address) -> Vec<ConsentGrant> {
+        let lenders: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ConsentLenders(owner.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut grants = Vec::new(&env);
+        for lender in lenders.iter() {
+            if let Some(grant) = env
+                .storage()
+                .persistent()
+                .get::<_, ConsentGrant>(&DataKey::Consent(owner.clone(), lender))
+            {
+                grants.push_back(grant);
+            }
+        }
+        grants
+    }
+
+    /// Who has read the owner's score, oldest first
+    pub fn get_access_log(env: Env, owner: Address) -> Vec<AccessLogEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccessLog(owner))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn set_decay_config(env: Env, admin: Address, config: DecayConfig) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        ScoreStorage::set_decay_config(&env, &config)
+    }
+
+    pub fn get_decay_config(env: Env) -> DecayConfig {
+        ScoreStorage::get_decay_config(&env)
+    }
+
+    /// Store raw factors (`ph=96;ut=28;age=54;dlq=1`) consumed by `calculate_score`
     pub fn update_factors(
         env: Env,
         account_id: Address,
         factors: String,
     ) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         rate_limit!(env, account_id, "upd_factor",
             max: 20, window: 3600,
             strategy: FixedWindow, scope: Global);
@@ -283,7 +694,8 @@ impl CreditScoreContract {
         env: Env,
         account_id: Address,
         score: u32,
-    ) -> Result<(), AuthorizationError> {
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         rate_limit!(env, account_id, "set_score",
             max: 30, window: 3600,
             strategy: SlidingWindow, scope: PerUser);
@@ -291,11 +703,12 @@ impl CreditScoreContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .ok_or(AuthorizationError::NotInitialized)?;
+            .ok_or(ContractError::NotInitialized)?;
         admin.require_auth();
+        Self::require_not_frozen(&env, &account_id)?;
 
-        ScoreStorage::store_score(&env, &account_id, score, env.ledger().timestamp())
-            .map_err(|_| AuthorizationError::NotAuthorized)?;
+        let timestamp = env.ledger().timestamp();
+        Self::write_score(&env, &account_id, score, timestamp)?;
         
         StorageTracker::record_operation(
             &env, 
@@ -305,120 +718,988 @@ impl CreditScoreContract {
             true
         );
         
+        let reason_codes = Self::reason_engine(&env, &account_id).top_codes(DEFAULT_REASON_COUNT);
+        env.events().publish(
+            (Symbol::new(&env, "score_set"), account_id),
+            (score, timestamp, reason_codes),
+        );
+        
         Ok(())
     }
 
-    pub fn batch_calculate_scores(
+    pub fn register_oracle_key(
         env: Env,
-        accounts: Vec<Address>,
-        pair: TokenPair,
-    ) -> Result<Vec<u32>, ContractError> {
-        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "batch_calc"));
-        
-        let trading_data = Self::fetch_trading_data(&env, &pair)?;
-        let liquidity = Self::fetch_liquidity_metrics(&env, &pair)?;
-        
-        let aggregator = SignalAggregator::new(&env);
-        let signals = aggregator.aggregate(&trading_data, &liquidity);
-        
-        let mut scores = Vec::new(&env);
-        
-        for account in accounts.iter() {
-            let base_score = Self::get_base_score(&env, &account).unwrap_or(500);
-            let adjusted = aggregator.calculate_credit_impact(base_score, &signals);
-            scores.push_back(adjusted as u32);
-        }
-        
-        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "batch_calc"));
-        
-        Ok(scores)
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        let key = OracleKey {
+            public_key: public_key.clone(),
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::OracleKey(public_key.clone()), &key);
+        env.events().publish((symbol_short!("orcl_add"),), public_key);
+        Ok(())
     }
 
-    pub fn update_signal_weights(
+    pub fn revoke_oracle_key(
         env: Env,
         admin: Address,
-        weights: Vec<SignalWeight>,
+        public_key: BytesN<32>,
     ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
-        
-        let total_weight: u32 = weights.iter().map(|w| w.weight).sum();
-        if total_weight > 100 {
-            return Err(ContractError::InvalidConfiguration);
+        if !env.storage().persistent().has(&DataKey::OracleKey(public_key.clone())) {
+            return Err(ContractError::NotFound);
         }
-        
-        env.storage().instance().set(&Symbol::new(&env, "signal_weights"), &weights);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OracleKey(public_key.clone()));
+        env.events().publish((symbol_short!("orcl_rev"),), public_key);
         Ok(())
     }
 
-    pub fn invalidate_dex_cache(env: Env, admin: Address, pair: TokenPair) -> Result<(), ContractError> {
-        Self::require_admin(&env, &admin)?;
-        
-        let mut cache = DexDataCache::new(&env);
-        cache.invalidate(&pair);
-        
-        Ok(())
-    }
-    
-    pub fn get_score_history(env: Env, account_id: Address, limit: u32) -> Result<Vec<common_utils::storage_optimization::ScoreData>, AuthorizationError> {
-        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "get_score_history"));
-        
-        let result = ScoreStorage::get_score_history(&env, &account_id, limit)
-            .map_err(|_| AuthorizationError::NotAuthorized)?;
-        
-        StorageTracker::record_operation(
-            &env, 
-            &Symbol::new(&env, "access"), 
-            &Symbol::new(&env, "history"), 
-            0, 
-            false
-        );
-        
-        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "get_score_history"));
-        
-        Ok(result)
+    pub fn get_oracle_key(env: Env, public_key: BytesN<32>) -> Option<OracleKey> {
+        env.storage().persistent().get(&DataKey::OracleKey(public_key))
     }
-    
-    pub fn migrate_to_compressed(env: Env, admin: Address) -> Result<u64, ContractError> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(ContractError::NotInitialized)?;
-        
-        if stored_admin != admin {
+
+    /// Store a score signed by a registered oracle key. Anyone may relay the attestation.
+    pub fn submit_score(
+        env: Env,
+        attestation: ScoreAttestation,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        if !env.storage().persistent().has(&DataKey::OracleKey(public_key.clone())) {
             return Err(ContractError::Unauthorized);
         }
-        
-        if env.storage().instance().has(&DataKey::MigrationState) {
-            return Err(ContractError::InvalidState);
+
+        let now = env.ledger().timestamp();
+        if now >= attestation.expires_at {
+            return Err(ContractError::InvalidInput);
         }
-        
-        let addresses = Self::get_all_score_addresses(&env);
-        
-        if addresses.is_empty() {
-            return Ok(0);
+        if attestation.score > MAX_SCORE {
+            return Err(ContractError::InvalidInput);
         }
+
+        let nonce_key = DataKey::OracleNonce(public_key.clone(), attestation.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            return Err(ContractError::InvalidState);
+        }
+
+        // Panics if the signature does not match
+        let payload = (env.current_contract_address(), attestation.clone()).to_xdr(&env);
+        env.crypto().ed25519_verify(&public_key, &payload, &signature);
+
+        Self::require_not_frozen(&env, &attestation.account)?;
+
+        env.storage().persistent().set(&nonce_key, &now);
+        Self::write_score(&env, &attestation.account, attestation.score, now)?;
+        env.storage().persistent().set(
+            &DataKey::ScoreProvenance(attestation.account.clone()),
+            &ScoreProvenance {
+                public_key: public_key.clone(),
+                model_version: attestation.model_version,
+                nonce: attestation.nonce,
+                submitted_at: now,
+            },
+        );
+
+        StorageTracker::record_operation(
+            &env,
+            &Symbol::new(&env, "store"),
+            &Symbol::new(&env, "score"),
+            44,
+            true,
+        );
+
+        env.events().publish(
+            (symbol_short!("orcl_scr"), attestation.account),
+            (attestation.score, attestation.model_version, public_key),
+        );
+        Ok(())
+    }
+
+    pub fn is_nonce_used(env: Env, public_key: BytesN<32>, nonce: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::OracleNonce(public_key, nonce))
+    }
+
+    /// Oracle key and model version behind the account's latest oracle-submitted score
+    pub fn get_score_provenance(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+    ) -> Result<Option<ScoreProvenance>, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_prov")?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoreProvenance(account_id)))
+    }
+
+    /// Open a bulk import job expecting `total_chunks` chunks submitted in order
+    pub fn start_score_import(
+        env: Env,
+        admin: Address,
+        total_chunks: u32,
+        strategy: RollbackStrategy,
+    ) -> Result<u64, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        if total_chunks == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let id: u64 = env.storage().instance().get(&DataKey::ImportCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ImportCount, &id);
+
+        let job = ImportJob {
+            id,
+            strategy,
+            total_chunks,
+            next_chunk: 0,
+            imported: 0,
+            failed: 0,
+            skipped: 0,
+            created_at: env.ledger().timestamp(),
+            completed_at: None,
+        };
+        env.storage().persistent().set(&DataKey::ImportJob(id), &job);
+
+        env.events().publish((symbol_short!("imp_start"), id), total_chunks);
+        Ok(id)
+    }
+
+    /// Import one chunk of at most `MAX_BATCH_SIZE` rows. `chunk_index` must be the job's next chunk.
+    pub fn import_score_chunk(
+        env: Env,
+        admin: Address,
+        job_id: u64,
+        chunk_index: u32,
+        rows: Vec<ScoreImportRow>,
+    ) -> Result<ImportChunkResult, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "import_chunk"));
+
+        let mut job: ImportJob = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ImportJob(job_id))
+            .ok_or(ContractError::NotFound)?;
+        if job.is_complete() || chunk_index != job.next_chunk {
+            return Err(ContractError::InvalidState);
+        }
+        if rows.is_empty() || rows.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Validate every row before writing anything
+        let now = env.ledger().timestamp();
+        let mut row_errors: Vec<u32> = Vec::new(&env);
+        let mut has_failures = false;
+        for (i, row) in rows.iter().enumerate() {
+            let duplicate = rows
+                .iter()
+                .take(i)
+                .any(|earlier| earlier.account == row.account);
+            let code = if row.score > MAX_SCORE {
+                bulk_import::ROW_SCORE_OUT_OF_RANGE
+            } else if row.timestamp == 0 || row.timestamp > now {
+                bulk_import::ROW_TIMESTAMP_INVALID
+            } else if duplicate {
+                bulk_import::ROW_DUPLICATE_ACCOUNT
+            } else if Self::require_not_frozen(&env, &row.account).is_err() {
+                bulk_import::ROW_SCORE_FROZEN
+            } else if ScoreStorage::get_raw_score(&env, &row.account)
+                .map(|stored| stored.timestamp > row.timestamp)
+                .unwrap_or(false)
+            {
+                bulk_import::ROW_STALE
+            } else {
+                0
+            };
+            has_failures |= code != 0 && code != bulk_import::ROW_STALE;
+            row_errors.push_back(code);
+        }
+
+        let mut results = Vec::new(&env);
+        let mut succeeded = 0;
+        let mut failed = 0;
+        let mut skipped = 0;
+
+        if has_failures && job.strategy == RollbackStrategy::AllOrNothing {
+            for (i, code) in row_errors.iter().enumerate() {
+                let failed_row = code != 0 && code != bulk_import::ROW_STALE;
+                results.push_back(OperationResult {
+                    index: i as u32,
+                    status: if failed_row {
+                        OperationStatus::Failed
+                    } else {
+                        OperationStatus::RolledBack
+                    },
+                    error_code: if failed_row { code } else { 0 },
+                });
+                if failed_row {
+                    failed += 1;
+                }
+            }
+
+            env.events().publish((symbol_short!("imp_rb"), job_id), (chunk_index, failed));
+
+            return Ok(ImportChunkResult {
+                job_id,
+                chunk_index,
+                succeeded: 0,
+                failed,
+                skipped: 0,
+                rolled_back: true,
+                results,
+            });
+        }
+
+        for (i, (row, code)) in rows.iter().zip(row_errors.iter()).enumerate() {
+            let status = if code == 0 {
+                Self::write_score(&env, &row.account, row.score, row.timestamp)?;
+                succeeded += 1;
+                OperationStatus::Success
+            } else if code == bulk_import::ROW_STALE {
+                skipped += 1;
+                OperationStatus::Skipped
+            } else {
+                failed += 1;
+                OperationStatus::Failed
+            };
+            results.push_back(OperationResult {
+                index: i as u32,
+                status,
+                error_code: code,
+            });
+        }
+
+        job.next_chunk += 1;
+        job.imported += succeeded;
+        job.failed += failed;
+        job.skipped += skipped;
+        if job.is_complete() {
+            job.completed_at = Some(now);
+        }
+        env.storage().persistent().set(&DataKey::ImportJob(job_id), &job);
+
+        StorageTracker::record_operation(
+            &env,
+            &Symbol::new(&env, "store"),
+            &Symbol::new(&env, "score"),
+            44 * succeeded,
+            true,
+        );
+
+        env.events().publish(
+            (symbol_short!("imp_chunk"), job_id),
+            (chunk_index, succeeded, failed, skipped),
+        );
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "import_chunk"));
+
+        Ok(ImportChunkResult {
+            job_id,
+            chunk_index,
+            succeeded,
+            failed,
+            skipped,
+            rolled_back: false,
+            results,
+        })
+    }
+
+    pub fn get_score_import(env: Env, job_id: u64) -> Option<ImportJob> {
+        env.storage().persistent().get(&DataKey::ImportJob(job_id))
+    }
+
+    /// Replace the score bands. Counts are kept per score bucket, so no account is revisited.
+    pub fn set_score_bands(
+        env: Env,
+        admin: Address,
+        bands: Vec<ScoreBand>,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        bands::validate_bands(&bands)?;
+
+        env.storage().instance().set(&DataKey::ScoreBands, &bands);
+
+        env.events().publish((symbol_short!("bands_set"),), bands.len());
+        Ok(())
+    }
+
+    pub fn get_score_bands(env: Env) -> Vec<ScoreBand> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoreBands)
+            .unwrap_or_else(|| bands::default_bands(&env))
+    }
+
+    /// Band containing the account's stored score. Decay is not applied, so the
+    /// band always agrees with [`Self::get_band_counts`].
+    pub fn get_score_band(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+    ) -> Result<ScoreBand, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_band")?;
+        let score = ScoreStorage::get_raw_score(&env, &account_id)?.score;
+        let bands = Self::get_score_bands(env.clone());
+        Ok(bands.get(bands::band_index(&bands, score)).unwrap())
+    }
+
+    /// Number of accounts per band. Counts use each account's latest stored
+    /// score without decay, which is what an account's band reflects as well;
+    /// an idle account only moves band when it is next rescored.
+    pub fn get_band_counts(env: Env) -> Vec<BandCount> {
+        let bands = Self::get_score_bands(env.clone());
+        let counts = bands::counts_from_histogram(&env, &bands, &Self::score_histogram(&env));
+
+        let mut result = Vec::new(&env);
+        for (band, accounts) in bands.iter().zip(counts.iter()) {
+            result.push_back(BandCount { band, accounts });
+        }
+        result
+    }
+
+    /// Delete an account's stored score, breakdown and signals and drop it from the index
+    pub fn delete_score(env: Env, admin: Address, account_id: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        Self::require_not_frozen(&env, &account_id)?;
+
+        let previous = ScoreStorage::get_raw_score(&env, &account_id)?;
+        ScoreStorage::remove_score(&env, &account_id);
+        env.storage().persistent().remove(&DataKey::Score(account_id.clone()));
+        env.storage().persistent().remove(&DataKey::ScoreBreakdown(account_id.clone()));
+        env.storage().instance().remove(&DataKey::ScoreSignals(account_id.clone()));
+        env.storage().instance().remove(&DataKey::ScoreSignalsVersion(account_id.clone()));
+        ScoredAccountIndex::remove(&env, &account_id);
+
+        let mut histogram = Self::score_histogram(&env);
+        let bucket = bands::bucket(previous.score);
+        histogram.set(bucket, histogram.get(bucket).unwrap().saturating_sub(1));
+        env.storage().instance().set(&DataKey::ScoreHistogram, &histogram);
+
+        env.events().publish((symbol_short!("score_del"), account_id), previous.score);
+        Ok(())
+    }
+
+    /// Page through every account with a stored score
+    pub fn get_scored_accounts(
+        env: Env,
+        admin: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<ScoredAccountPage, ContractError> {
+        Self::require_admin(&env, &admin)?;
+        Ok(ScoredAccountIndex::page(&env, cursor, limit))
+    }
+
+    pub fn get_scored_account_count(env: Env) -> u32 {
+        ScoredAccountIndex::len(&env)
+    }
+
+    /// Drop index entries whose score no longer exists (e.g. expired storage).
+    /// Returns the number of entries removed from the page.
+    pub fn prune_score_index(
+        env: Env,
+        admin: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let page = ScoredAccountIndex::page(&env, cursor, limit);
+        let mut pruned = 0;
+        for account in page.accounts.iter() {
+            if !ScoreStorage::has_score(&env, &account) && ScoredAccountIndex::remove(&env, &account) {
+                pruned += 1;
+            }
+        }
+        Ok(pruned)
+    }
+
+    /// Recalculate scores from stored factors for one page of the index.
+    /// Accounts without factors or with a frozen score are skipped.
+    pub fn rescore_accounts(
+        env: Env,
+        admin: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<RescoreResult, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "rescore"));
+
+        let page = ScoredAccountIndex::page(&env, cursor, limit);
+        let mut rescored = 0;
+        let mut skipped = 0;
+        for account in page.accounts.iter() {
+            match Self::calculate_score(env.clone(), account) {
+                Ok(_) => rescored += 1,
+                Err(_) => skipped += 1,
+            }
+        }
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "rescore"));
+
+        Ok(RescoreResult {
+            rescored,
+            skipped,
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    pub fn set_acl_contract(env: Env, admin: Address, acl_contract: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::AclContract, &acl_contract);
+        Ok(())
+    }
+
+    /// Reject callers blocked on `watchlist` from reads, consent grants and disputes. `None` disables the check.
+    pub fn set_watchlist(env: Env, admin: Address, watchlist: Option<Address>) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        match watchlist {
+            Some(watchlist) => env.storage().instance().set(&DataKey::Watchlist, &watchlist),
+            None => env.storage().instance().remove(&DataKey::Watchlist),
+        }
+        Ok(())
+    }
+
+    pub fn get_watchlist(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Watchlist)
+    }
+
+    /// Contest the account's current score. Freezes the score until resolved.
+    pub fn open_dispute(
+        env: Env,
+        account_id: Address,
+        evidence_cid: Bytes,
+    ) -> Result<u64, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        account_id.require_auth();
+        Self::require_not_blocked(&env, &account_id)?;
+
+        CIDValidator::new()
+            .validate(&env, &evidence_cid)
+            .map_err(|_| ContractError::InvalidInput)?;
+
+        if env.storage().persistent().has(&DataKey::OpenDispute(account_id.clone())) {
+            return Err(ContractError::InvalidState);
+        }
+
+        let current = ScoreStorage::get_raw_score(&env, &account_id)?;
+        let now = env.ledger().timestamp();
+
+        let dispute_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DisputeCount)
+            .unwrap_or(0u64)
+            + 1;
+        env.storage().instance().set(&DataKey::DisputeCount, &dispute_id);
+
+        let dispute = Dispute {
+            id: dispute_id,
+            account: account_id.clone(),
+            disputed_score: current.score,
+            evidence_cid: evidence_cid.clone(),
+            status: DisputeStatus::Open,
+            opened_at: now,
+            reviewer: None,
+            corrected_score: None,
+            resolved_at: None,
+        };
+        env.storage().persistent().set(&DataKey::Dispute(dispute_id), &dispute);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OpenDispute(account_id.clone()), &dispute_id);
+
+        let mut account_disputes: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountDisputes(account_id.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        account_disputes.push_back(dispute_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AccountDisputes(account_id.clone()), &account_disputes);
+
+        Self::record_dispute_audit(
+            &env,
+            dispute_id,
+            DisputeAction::Opened,
+            &account_id,
+            current.score,
+            current.score,
+        );
+
+        env.events().publish(
+            (symbol_short!("disp_open"), account_id),
+            (dispute_id, current.score, evidence_cid),
+        );
+
+        Ok(dispute_id)
+    }
+
+    /// Resolve an open dispute. `Some(score)` corrects the score, `None` rejects the dispute.
+    pub fn resolve_dispute(
+        env: Env,
+        reviewer: Address,
+        dispute_id: u64,
+        corrected_score: Option<u32>,
+    ) -> Result<DisputeStatus, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        reviewer.require_auth();
+
+        let acl: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AclContract)
+            .ok_or(ContractError::NotInitialized)?;
+
+        if !common_utils::check_permission(
+            env.clone(),
+            acl,
+            reviewer.clone(),
+            symbol_short!("score"),
+            symbol_short!("resolve"),
+        ) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let mut dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(ContractError::NotFound)?;
+
+        if dispute.status != DisputeStatus::Open {
+            return Err(ContractError::InvalidState);
+        }
+
+        let now = env.ledger().timestamp();
+        let (action, score_after) = match corrected_score {
+            Some(score) => {
+                if score > MAX_SCORE {
+                    return Err(ContractError::InvalidInput);
+                }
+                Self::write_score(&env, &dispute.account, score, now)?;
+                dispute.status = DisputeStatus::Corrected;
+                (DisputeAction::Corrected, score)
+            }
+            None => {
+                dispute.status = DisputeStatus::Rejected;
+                (DisputeAction::Rejected, dispute.disputed_score)
+            }
+        };
+
+        dispute.reviewer = Some(reviewer.clone());
+        dispute.corrected_score = corrected_score;
+        dispute.resolved_at = Some(now);
+
+        env.storage().persistent().set(&DataKey::Dispute(dispute_id), &dispute);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OpenDispute(dispute.account.clone()));
+
+        Self::record_dispute_audit(
+            &env,
+            dispute_id,
+            action,
+            &reviewer,
+            dispute.disputed_score,
+            score_after,
+        );
+
+        env.events().publish(
+            (symbol_short!("disp_res"), dispute.account.clone()),
+            (dispute_id, dispute.status, score_after, reviewer),
+        );
+
+        Ok(dispute.status)
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Option<Dispute> {
+        env.storage().persistent().get(&DataKey::Dispute(dispute_id))
+    }
+
+    pub fn get_open_dispute(env: Env, account_id: Address) -> Option<Dispute> {
+        let dispute_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OpenDispute(account_id))?;
+        env.storage().persistent().get(&DataKey::Dispute(dispute_id))
+    }
+
+    pub fn get_account_disputes(env: Env, account_id: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccountDisputes(account_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_dispute_audit(env: Env, dispute_id: u64) -> Vec<DisputeAuditEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeAudit(dispute_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Freeze an account's score on behalf of the fraud-detect contract set in `set_risk_contracts`,
+    /// e.g. when its trading is analyzed as critical risk. Only the admin can lift the freeze.
+    pub fn freeze_score(env: Env, account_id: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let fraud_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FraudContract)
+            .ok_or(ContractError::Unauthorized)?;
+        fraud_contract.require_auth();
+
+        let now = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoreFreeze(account_id.clone()), &now);
+        env.events().publish((symbol_short!("frozen"), account_id), now);
+        Ok(())
+    }
+
+    pub fn unfreeze_score(env: Env, admin: Address, account_id: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::ScoreFreeze(account_id.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::NotFound);
+        }
+        env.storage().persistent().remove(&key);
+        env.events().publish((symbol_short!("unfrozen"), account_id), admin);
+        Ok(())
+    }
+
+    /// True while the score is frozen by an open dispute or a fraud escalation
+    pub fn is_score_frozen(env: Env, account_id: Address) -> bool {
+        Self::require_not_frozen(&env, &account_id).is_err()
+    }
+
+    pub fn batch_calculate_scores(
+        env: Env,
+        accounts: Vec<Address>,
+        pair: TokenPair,
+    ) -> Result<Vec<u32>, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "batch_calc"));
+        
+        let trading_data = Self::fetch_trading_data(&env, &pair)?;
+        let liquidity = Self::fetch_liquidity_metrics(&env, &pair)?;
+        
+        let aggregator = SignalAggregator::load(&env);
+        let signals = aggregator.aggregate(&trading_data, &liquidity);
+        
+        let mut scores = Vec::new(&env);
+        
+        for account in accounts.iter() {
+            let base_score = Self::get_base_score(&env, &account).unwrap_or(500);
+            let adjusted = aggregator.calculate_credit_impact(base_score, &signals);
+            scores.push_back(adjusted as u32);
+        }
+        
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "batch_calc"));
+        
+        Ok(scores)
+    }
+
+    /// Score an account across every supported pair, weighting pairs by volume and liquidity health
+    pub fn calculate_score_multi_pair(
+        env: Env,
+        account_id: Address,
+    ) -> Result<MultiPairScore, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "calc_multi_pair"));
+
+        let base_score = Self::get_base_score(&env, &account_id)?;
+        let contributions = Self::score_supported_pairs(&env);
+
+        let aggregator = SignalAggregator::load(&env);
+        let result = aggregator.combine_pairs(base_score, &contributions);
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "calc_multi_pair"));
+
+        Ok(result)
+    }
+
+    pub fn batch_calculate_scores_multi_pair(
+        env: Env,
+        accounts: Vec<Address>,
+    ) -> Result<Vec<MultiPairScore>, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "batch_multi_pair"));
+
+        // Pair data is pool-level, so it is fetched once for the whole batch
+        let contributions = Self::score_supported_pairs(&env);
+        let aggregator = SignalAggregator::load(&env);
+
+        let mut results = Vec::new(&env);
+        for account in accounts.iter() {
+            let base_score = Self::get_base_score(&env, &account).unwrap_or(500);
+            results.push_back(aggregator.combine_pairs(base_score, &contributions));
+        }
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "batch_multi_pair"));
+
+        Ok(results)
+    }
+
+    pub fn add_indexer(env: Env, admin: Address, indexer: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Indexer(indexer.clone()), &true);
+        env.events().publish((symbol_short!("idx_add"),), indexer);
+        Ok(())
+    }
+
+    pub fn remove_indexer(env: Env, admin: Address, indexer: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::Indexer(indexer.clone()));
+        env.events().publish((symbol_short!("idx_rem"),), indexer);
+        Ok(())
+    }
+
+    /// Append trades to an account's ledger. Only registered indexers may call this.
+    pub fn record_account_trades(
+        env: Env,
+        indexer: Address,
+        account_id: Address,
+        trades: Vec<AccountTrade>,
+    ) -> Result<u64, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        indexer.require_auth();
+        if !env.storage().instance().has(&DataKey::Indexer(indexer.clone())) {
+            return Err(ContractError::Unauthorized);
+        }
+        if trades.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut ledger: AccountTradingLedger = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountLedger(account_id.clone()))
+            .unwrap_or_else(|| AccountTradingLedger::new(&env, account_id.clone()));
+
+        for trade in trades.iter() {
+            if !trade.is_valid() {
+                return Err(ContractError::InvalidInput);
+            }
+            ledger.record(trade);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::AccountLedger(account_id.clone()), &ledger);
+
+        StorageTracker::record_operation(
+            &env,
+            &Symbol::new(&env, "store"),
+            &Symbol::new(&env, "acct_trades"),
+            trades.len() * 64,
+            true,
+        );
+
+        env.events().publish(
+            (symbol_short!("acct_trd"), account_id),
+            (trades.len(), ledger.trade_count),
+        );
+
+        Ok(ledger.trade_count)
+    }
+
+    pub fn get_account_ledger(env: Env, account_id: Address) -> Option<AccountTradingLedger> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccountLedger(account_id))
+    }
+
+    /// Replace the signal weight set. Returns the new weight set version.
+    pub fn update_signal_weights(
+        env: Env,
+        admin: Address,
+        weights: Vec<SignalWeight>,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        
+        if weights.is_empty() {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        // Each signal type at most once; types left out are weighted 0
+        let mut total_weight: u32 = 0;
+        for (index, weight) in weights.iter().enumerate() {
+            if weights
+                .iter()
+                .skip(index + 1)
+                .any(|other| other.signal_type == weight.signal_type)
+            {
+                return Err(ContractError::InvalidConfiguration);
+            }
+            if weight.decay_factor > 10000 || weight.min_value >= weight.max_value {
+                return Err(ContractError::InvalidConfiguration);
+            }
+            total_weight = total_weight
+                .checked_add(weight.weight)
+                .ok_or(ContractError::InvalidConfiguration)?;
+        }
+        if total_weight > 100 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        
+        let version = SignalWeightStore::save(&env, weights);
+        env.events().publish((symbol_short!("sig_wts"),), version);
+        Ok(version)
+    }
+
+    /// Current weight set, or a specific historical version
+    pub fn get_signal_weights(env: Env, version: Option<u32>) -> Option<SignalWeightSet> {
+        match version {
+            Some(version) => SignalWeightStore::get(&env, version),
+            None => Some(SignalWeightStore::current(&env)),
+        }
+    }
+
+    pub fn invalidate_dex_cache(env: Env, admin: Address, pair: TokenPair) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        
+        let mut cache = DexDataCache::new(&env);
+        cache.invalidate(&pair);
+        
+        Ok(())
+    }
+    
+    pub fn get_score_history(env: Env, caller: Address, account_id: Address, limit: u32) -> Result<Vec<common_utils::storage_optimization::ScoreData>, AuthorizationError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_history")
+            .map_err(|_| AuthorizationError::NotAuthorized)?;
+
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "get_score_history"));
+        
+        let result = ScoreStorage::get_score_history(&env, &account_id, limit)
+            .map_err(|_| AuthorizationError::NotAuthorized)?;
+        
+        StorageTracker::record_operation(
+            &env, 
+            &Symbol::new(&env, "access"), 
+            &Symbol::new(&env, "history"), 
+            0, 
+            false
+        );
+        
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "get_score_history"));
+        
+        Ok(result)
+    }
+    
+    /// Score history recorded between `from` and `to` (inclusive), oldest first
+    pub fn get_score_history_range(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+        from: u64,
+        to: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<ScoreHistoryPage, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_history")?;
+        if from > to {
+            return Err(ContractError::InvalidInput);
+        }
+        ScoreStorage::get_score_history_range(&env, &account_id, from, to, cursor, limit)
+    }
+
+    /// Statistics over the last `window_seconds` of score history, e.g. 90 days
+    pub fn get_score_trend(
+        env: Env,
+        caller: Address,
+        account_id: Address,
+        window_seconds: u64,
+    ) -> Result<ScoreHistoryStats, ContractError> {
+        Self::require_read_access(&env, &caller, &account_id, "get_trend")?;
+        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "get_score_trend"));
+
+        let now = env.ledger().timestamp();
+        let stats = ScoreStorage::get_score_history_stats(
+            &env,
+            &account_id,
+            now.saturating_sub(window_seconds),
+            now,
+        )?;
+
+        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "get_score_trend"));
+
+        Ok(stats)
+    }
+    
+    /// Compress the scores of one page of the scored account index. Returns the
+    /// migration id (0 when the page is empty) and the cursor of the next page.
+    pub fn migrate_to_compressed(
+        env: Env,
+        admin: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<(u64, Option<u32>), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        
+        if env.storage().instance().has(&DataKey::MigrationState) {
+            return Err(ContractError::InvalidState);
+        }
+        
+        let page = ScoredAccountIndex::page(&env, cursor, limit);
+        let addresses = page.accounts;
+        
+        if addresses.is_empty() {
+            return Ok((0, page.next_cursor));
+        }
+        
+        let config = MigrationConfig {
+            batch_size: 20,
+            max_retries: 3,
+            rollback_enabled: true,
+            validation_enabled: true,
+            compression_type: CompressionType::BitPacking,
+            dry_run: false,
+        };
+        
+        let mut data_keys = Vec::new(&env);
+        for address in addresses.iter() {
+            data_keys.push_back(ScoreStorage::score_key(&address));
+        }
+        
+        let migration_id = DataMigrationManager::start_migration(&env, &config, &data_keys)?;
+        
+        env.storage().instance().set(&DataKey::MigrationState, &migration_id);
         
-        let config = MigrationConfig {
-            batch_size: 20,
-            max_retries: 3,
-            rollback_enabled: true,
-            validation_enabled: true,
-            compression_type: CompressionType::BitPacking,
-            dry_run: false,
-        };
-        
-        let migration_id = DataMigrationManager::start_migration(&env, &config, &addresses)?;
-        
-        env.storage().instance().set(&DataKey::MigrationState, &migration_id);
-        
         DataMigrationManager::execute_migration(&env, migration_id)?;
         
         Self::cleanup_uncompressed_scores(&env, &addresses)?;
         
         env.storage().instance().remove(&DataKey::MigrationState);
         
-        Ok(migration_id)
+        Ok((migration_id, page.next_cursor))
     }
     
     pub fn get_efficiency_report(env: Env) -> Result<common_utils::storage_monitoring::StorageEfficiencyReport, ContractError> {
@@ -445,12 +1726,214 @@ impl CreditScoreContract {
         Ok(())
     }
 
+    fn require_not_blocked(env: &Env, caller: &Address) -> Result<(), ContractError> {
+        if let Some(watchlist) = env.storage().instance().get::<_, Address>(&DataKey::Watchlist) {
+            if watchlist::is_blocked_on(env, &watchlist, caller) {
+                return Err(ContractError::Unauthorized);
+            }
+        }
+        Ok(())
+    }
+
+    /// Owner, admin, or a lender with active consent may read. Non-owner reads are logged.
+    fn require_read_access(
+        env: &Env,
+        caller: &Address,
+        account_id: &Address,
+        function: &str,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        Self::require_not_blocked(env, caller)?;
+
+        if caller == account_id {
+            return Ok(());
+        }
+
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        let purpose = if admin.as_ref() == Some(caller) {
+            None
+        } else {
+            let grant: ConsentGrant = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Consent(account_id.clone(), caller.clone()))
+                .ok_or(ContractError::Unauthorized)?;
+            if !grant.is_active(env.ledger().timestamp()) {
+                return Err(ContractError::Unauthorized);
+            }
+            Some(grant.purpose)
+        };
+
+        let mut log: Vec<AccessLogEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccessLog(account_id.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        if log.len() >= MAX_ACCESS_LOG {
+            log.pop_front();
+        }
+        log.push_back(AccessLogEntry {
+            accessor: caller.clone(),
+            function: Symbol::new(env, function),
+            purpose,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::AccessLog(account_id.clone()), &log);
+
+        Ok(())
+    }
+
+    fn require_not_frozen(env: &Env, account_id: &Address) -> Result<(), ContractError> {
+        if env.storage().persistent().has(&DataKey::OpenDispute(account_id.clone()))
+            || env.storage().persistent().has(&DataKey::ScoreFreeze(account_id.clone()))
+        {
+            return Err(ContractError::InvalidState);
+        }
+        Ok(())
+    }
+
+    fn record_dispute_audit(
+        env: &Env,
+        dispute_id: u64,
+        action: DisputeAction,
+        actor: &Address,
+        score_before: u32,
+        score_after: u32,
+    ) {
+        let mut trail: Vec<DisputeAuditEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeAudit(dispute_id))
+            .unwrap_or_else(|| Vec::new(env));
+        trail.push_back(DisputeAuditEntry {
+            action,
+            actor: actor.clone(),
+            score_before,
+            score_after,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&DataKey::DisputeAudit(dispute_id), &trail);
+    }
+
+    /// Store a score, keep the per-band counts in sync and announce band changes.
+    /// The factor breakdown and DEX signals explained the previous score, so
+    /// they are dropped; `calculate_score` stores the new breakdown afterwards.
+    fn write_score(
+        env: &Env,
+        account_id: &Address,
+        score: u32,
+        timestamp: u64,
+    ) -> Result<(), ContractError> {
+        let previous = ScoreStorage::get_raw_score(env, account_id).ok();
+        ScoreStorage::store_score(env, account_id, score, timestamp)?;
+        ScoredAccountIndex::insert(env, account_id);
+        // Provenance describes oracle submissions only; `submit_score` sets it after writing
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoreProvenance(account_id.clone()));
+        env.storage().persistent().remove(&DataKey::ScoreBreakdown(account_id.clone()));
+        env.storage().instance().remove(&DataKey::ScoreSignals(account_id.clone()));
+        env.storage().instance().remove(&DataKey::ScoreSignalsVersion(account_id.clone()));
+
+        let new_bucket = bands::bucket(score);
+        let old_bucket = previous.as_ref().map(|data| bands::bucket(data.score));
+        if old_bucket != Some(new_bucket) {
+            let mut histogram = Self::score_histogram(env);
+            if let Some(old) = old_bucket {
+                histogram.set(old, histogram.get(old).unwrap().saturating_sub(1));
+            }
+            histogram.set(new_bucket, histogram.get(new_bucket).unwrap() + 1);
+            env.storage().instance().set(&DataKey::ScoreHistogram, &histogram);
+        }
+
+        let bands = Self::get_score_bands(env.clone());
+        let new_band = bands::band_index(&bands, score);
+        let old_band = previous.map(|data| bands::band_index(&bands, data.score));
+        if old_band == Some(new_band) {
+            return Ok(());
+        }
+
+        env.events().publish(
+            (symbol_short!("band_chg"), account_id.clone()),
+            (
+                old_band.map(|old| bands.get(old).unwrap().name),
+                bands.get(new_band).unwrap().name,
+                score,
+            ),
+        );
+        Ok(())
+    }
+
+    /// Accounts per score bucket, by latest stored score
+    fn score_histogram(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoreHistogram)
+            .unwrap_or_else(|| bands::empty_histogram(env))
+    }
+
+    /// Reason engine loaded with the account's stored factor breakdown and DEX signals
+    fn reason_engine<'a>(env: &'a Env, account_id: &Address) -> ReasonEngine<'a> {
+        let mut engine = ReasonEngine::new(env);
+
+        let breakdown: Option<ScoreBreakdown> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoreBreakdown(account_id.clone()));
+        if let Some(breakdown) = breakdown {
+            engine = engine.with_breakdown(&breakdown, &Self::get_scoring_config(env.clone()));
+        }
+
+        let signals: Option<Vec<ScoringSignal>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScoreSignals(account_id.clone()));
+        if let Some(signals) = signals {
+            engine = engine.with_signals(&SignalAggregator::load(env), &signals);
+        }
+
+        engine
+    }
+
     fn get_base_score(env: &Env, account_id: &Address) -> Result<u32, ContractError> {
         ScoreStorage::get_score(env, account_id)
             .map(|s| s)
             .unwrap_or(500)
     }
 
+    /// Per-pair DEX scores for every supported pair. Pairs whose data cannot be fetched are skipped.
+    fn score_supported_pairs(env: &Env) -> Vec<PairContribution> {
+        let mut contributions = Vec::new(env);
+
+        let dex_enabled: bool = env.storage().instance()
+            .get(&DataKey::DexEnabled)
+            .unwrap_or(false);
+        if !dex_enabled {
+            return contributions;
+        }
+
+        let pairs: Vec<TokenPair> = env.storage().instance()
+            .get(&DataKey::SupportedPairs)
+            .unwrap_or_else(|| Vec::new(env));
+
+        let aggregator = SignalAggregator::load(env);
+        for pair in pairs.iter() {
+            let trading_data = match Self::fetch_trading_data(env, &pair) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            let liquidity = match Self::fetch_liquidity_metrics(env, &pair) {
+                Ok(metrics) => metrics,
+                Err(_) => continue,
+            };
+            contributions.push_back(aggregator.score_pair(&trading_data, &liquidity));
+        }
+
+        contributions
+    }
+
     fn fetch_trading_data(env: &Env, pair: &TokenPair) -> Result<TradingData, ContractError> {
         let mut cache = DexDataCache::new(env);
         
@@ -510,14 +1993,9 @@ impl CreditScoreContract {
         Ok(metrics)
     }
     
-    fn get_all_score_addresses(env: &Env) -> Vec<Symbol> {
-        Vec::new(env)
-    }
-    
-    fn cleanup_uncompressed_scores(env: &Env, addresses: &Vec<Symbol>) -> Result<(), ContractError> {
-        for address_symbol in addresses.iter() {
-            let old_key = DataKey::Score(Address::from_bytes(&Bytes::from_slice(env, address_symbol.to_string().as_bytes())));
-            env.storage().persistent().remove(&old_key);
+    fn cleanup_uncompressed_scores(env: &Env, addresses: &Vec<Address>) -> Result<(), ContractError> {
+        for address in addresses.iter() {
+            env.storage().persistent().remove(&DataKey::Score(address));
         }
         Ok(())
     }
@@ -529,6 +2007,18 @@ pub struct ScoreWithSignals {
     pub base_score: u32,
     pub dex_adjustment: i32,
     pub signals: Vec<ScoringSignal>,
+    pub is_stale: bool,
+    pub weights_version: u32,
+    pub reasons: Vec<ScoreReason>,
+}
+
+/// Outcome of one `rescore_accounts` page
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RescoreResult {
+    pub rescored: u32,
+    pub skipped: u32,
+    pub next_cursor: Option<u32>,
 }
 
 #[cfg(test)]
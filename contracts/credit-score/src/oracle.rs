@@ -0,0 +1,37 @@
+//! # Oracle Score Attestations
+//!
+//! Off-chain scoring models publish scores through registered oracle keys
+//! instead of the admin signing every `set_score`. An attestation is signed
+//! with ed25519 over the XDR encoding of
+//! `(credit_score_contract_address, ScoreAttestation)`, which binds it to
+//! this contract. Each nonce can be used once per oracle key and the
+//! attestation is rejected after `expires_at`.
+
+use soroban_sdk::{contracttype, Address, BytesN};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreAttestation {
+    pub account: Address,
+    pub score: u32,
+    pub model_version: u32,
+    pub nonce: u64,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OracleKey {
+    pub public_key: BytesN<32>,
+    pub registered_at: u64,
+}
+
+/// Where an account's latest oracle-submitted score came from
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreProvenance {
+    pub public_key: BytesN<32>,
+    pub model_version: u32,
+    pub nonce: u64,
+    pub submitted_at: u64,
+}
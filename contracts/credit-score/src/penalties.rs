@@ -0,0 +1,161 @@
+//! # External Risk Penalties
+//!
+//! When configured with the addresses of the fraud-detect and risk-eval
+//! contracts, `calculate_score` asks them about the account and deducts
+//! admin-configured penalties from the factor score:
+//!
+//! - fraud-detect `get_latest_score(agent_id)` (0-100) - scaled penalty once
+//!   the fraud score reaches `fraud_threshold`. fraud-detect keys agents by
+//!   `Symbol`, so only accounts linked to an agent id are checked.
+//! - risk-eval `get_risk(account)` - a flat penalty per risk level.
+//!
+//! A query that fails (contract paused, wrong address, missing function) is
+//! skipped with a `pen_skip` event rather than failing the score.
+
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol};
+
+use crate::scoring::MAX_SCORE;
+
+/// Mirror of risk-eval's `RiskLevel`, decoded from `get_risk` results
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PenaltyConfig {
+    /// Fraud score (0-100) from which the fraud penalty applies
+    pub fraud_threshold: u32,
+    /// Penalty at a fraud score of 100; lower fraud scores are scaled linearly
+    pub max_fraud_penalty: u32,
+    pub medium_risk_penalty: u32,
+    pub high_risk_penalty: u32,
+    pub critical_risk_penalty: u32,
+}
+
+impl Default for PenaltyConfig {
+    fn default() -> Self {
+        Self {
+            fraud_threshold: 50,
+            max_fraud_penalty: 200,
+            medium_risk_penalty: 25,
+            high_risk_penalty: 100,
+            critical_risk_penalty: 400,
+        }
+    }
+}
+
+impl PenaltyConfig {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.fraud_threshold > 100 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        for penalty in [
+            self.max_fraud_penalty,
+            self.medium_risk_penalty,
+            self.high_risk_penalty,
+            self.critical_risk_penalty,
+        ] {
+            if penalty > MAX_SCORE {
+                return Err(ContractError::InvalidConfiguration);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fraud_penalty(&self, fraud_score: u32) -> u32 {
+        if fraud_score == 0 || fraud_score < self.fraud_threshold {
+            return 0;
+        }
+        self.max_fraud_penalty * fraud_score.min(100) / 100
+    }
+
+    pub fn risk_penalty(&self, risk: RiskLevel) -> u32 {
+        match risk {
+            RiskLevel::Low => 0,
+            RiskLevel::Medium => self.medium_risk_penalty,
+            RiskLevel::High => self.high_risk_penalty,
+            RiskLevel::Critical => self.critical_risk_penalty,
+        }
+    }
+}
+
+/// External inputs consulted for a score and the penalties they produced
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedPenalties {
+    pub fraud_score: Option<u32>,
+    pub risk_level: Option<RiskLevel>,
+    pub fraud_penalty: u32,
+    pub risk_penalty: u32,
+}
+
+impl AppliedPenalties {
+    pub fn none() -> Self {
+        Self {
+            fraud_score: None,
+            risk_level: None,
+            fraud_penalty: 0,
+            risk_penalty: 0,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.fraud_penalty + self.risk_penalty
+    }
+}
+
+/// Query the configured contracts. Unset contracts, unlinked agents and
+/// failed queries are skipped.
+pub fn collect(
+    env: &Env,
+    config: &PenaltyConfig,
+    account: &Address,
+    fraud_contract: Option<Address>,
+    fraud_agent_id: Option<Symbol>,
+    risk_contract: Option<Address>,
+) -> AppliedPenalties {
+    let mut applied = AppliedPenalties::none();
+
+    if let (Some(contract), Some(agent_id)) = (fraud_contract, fraud_agent_id) {
+        match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            &contract,
+            &Symbol::new(env, "get_latest_score"),
+            soroban_sdk::vec![env, agent_id.into_val(env)],
+        ) {
+            Ok(Ok(fraud_score)) => {
+                applied.fraud_score = Some(fraud_score);
+                applied.fraud_penalty = config.fraud_penalty(fraud_score);
+            }
+            _ => skipped(env, account, symbol_short!("fraud"), contract),
+        }
+    }
+
+    if let Some(contract) = risk_contract {
+        match env.try_invoke_contract::<Option<RiskLevel>, soroban_sdk::Error>(
+            &contract,
+            &Symbol::new(env, "get_risk"),
+            soroban_sdk::vec![env, account.into_val(env)],
+        ) {
+            Ok(Ok(Some(level))) => {
+                applied.risk_level = Some(level);
+                applied.risk_penalty = config.risk_penalty(level);
+            }
+            Ok(Ok(None)) => {}
+            _ => skipped(env, account, symbol_short!("risk"), contract),
+        }
+    }
+
+    applied
+}
+
+fn skipped(env: &Env, account: &Address, source: Symbol, contract: Address) {
+    env.events()
+        .publish((symbol_short!("pen_skip"), account.clone()), (source, contract));
+}
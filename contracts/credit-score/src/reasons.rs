@@ -0,0 +1,205 @@
+//! # Score Reason Codes
+//!
+//! Adverse-action reasons for a score. Every credit factor and DEX signal is
+//! measured by how many score points it costs the account compared to a
+//! perfect value; the largest shortfalls are reported as standardized codes
+//! with a short description lenders can pass on to the applicant.
+
+use common_utils::dex::scoring_signals::{ScoringSignal, SignalAggregator, SignalType};
+use soroban_sdk::{contracttype, Env, String, Vec};
+
+use crate::scoring::{ScoreBreakdown, ScoringConfig};
+
+/// Number of reasons returned when the caller does not ask for a specific count
+pub const DEFAULT_REASON_COUNT: u32 = 4;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReasonCode {
+    LatePayments,
+    HighUtilization,
+    ShortCreditHistory,
+    Delinquencies,
+    LowTradingVolume,
+    ShallowLiquidity,
+    PriceInstability,
+    LowTradeFrequency,
+    HighSlippage,
+    UnstableLiquidity,
+    ThinMarketDepth,
+    HighPriceImpact,
+    DecliningVolume,
+    DecliningLiquidity,
+    FraudReports,
+    ElevatedRisk,
+}
+
+impl ReasonCode {
+    /// Stable numeric code: 1xx credit factors, 2xx DEX signals, 3xx external penalties
+    pub fn code(&self) -> u32 {
+        match self {
+            ReasonCode::LatePayments => 101,
+            ReasonCode::HighUtilization => 102,
+            ReasonCode::ShortCreditHistory => 103,
+            ReasonCode::Delinquencies => 104,
+            ReasonCode::LowTradingVolume => 201,
+            ReasonCode::ShallowLiquidity => 202,
+            ReasonCode::PriceInstability => 203,
+            ReasonCode::LowTradeFrequency => 204,
+            ReasonCode::HighSlippage => 205,
+            ReasonCode::UnstableLiquidity => 206,
+            ReasonCode::ThinMarketDepth => 207,
+            ReasonCode::HighPriceImpact => 208,
+            ReasonCode::DecliningVolume => 209,
+            ReasonCode::DecliningLiquidity => 210,
+            ReasonCode::FraudReports => 301,
+            ReasonCode::ElevatedRisk => 302,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReasonCode::LatePayments => "Payments not consistently made on time",
+            ReasonCode::HighUtilization => "Credit utilization is too high",
+            ReasonCode::ShortCreditHistory => "Length of credit history is too short",
+            ReasonCode::Delinquencies => "Recorded delinquencies on the account",
+            ReasonCode::LowTradingVolume => "Insufficient trading volume",
+            ReasonCode::ShallowLiquidity => "Trades in pools with shallow liquidity",
+            ReasonCode::PriceInstability => "Trades in volatile markets",
+            ReasonCode::LowTradeFrequency => "Too few trades recorded",
+            ReasonCode::HighSlippage => "Trades executed with high slippage",
+            ReasonCode::UnstableLiquidity => "Liquidity in traded pools is unstable",
+            ReasonCode::ThinMarketDepth => "Traded markets lack depth",
+            ReasonCode::HighPriceImpact => "Trades have a high price impact",
+            ReasonCode::DecliningVolume => "Trading volume is declining",
+            ReasonCode::DecliningLiquidity => "Liquidity in traded pools is declining",
+            ReasonCode::FraudReports => "Fraud reports filed against the account",
+            ReasonCode::ElevatedRisk => "Account assessed as elevated risk",
+        }
+    }
+
+    pub fn from_signal(signal_type: &SignalType) -> Self {
+        match signal_type {
+            SignalType::TradingVolume => ReasonCode::LowTradingVolume,
+            SignalType::LiquidityDepth => ReasonCode::ShallowLiquidity,
+            SignalType::PriceStability => ReasonCode::PriceInstability,
+            SignalType::TradeFrequency => ReasonCode::LowTradeFrequency,
+            SignalType::SlippageTolerance => ReasonCode::HighSlippage,
+            SignalType::LiquidityConsistency => ReasonCode::UnstableLiquidity,
+            SignalType::MarketDepth => ReasonCode::ThinMarketDepth,
+            SignalType::PriceImpact => ReasonCode::HighPriceImpact,
+            SignalType::VolumeTrend => ReasonCode::DecliningVolume,
+            SignalType::LiquidityTrend => ReasonCode::DecliningLiquidity,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreReason {
+    pub code: ReasonCode,
+    pub numeric_code: u32,
+    pub description: String,
+    /// Score points lost to this factor or signal
+    pub points_lost: u32,
+}
+
+pub struct ReasonEngine<'a> {
+    env: &'a Env,
+    candidates: Vec<(ReasonCode, u32)>,
+}
+
+impl<'a> ReasonEngine<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self {
+            env,
+            candidates: Vec::new(env),
+        }
+    }
+
+    /// Add the shortfall of each credit factor against its maximum points
+    pub fn with_breakdown(mut self, breakdown: &ScoreBreakdown, config: &ScoringConfig) -> Self {
+        let range = config.max_score.saturating_sub(config.min_score);
+        let max_points = |weight: u32| (weight as u64 * range as u64 / 100) as u32;
+
+        self.push(
+            ReasonCode::LatePayments,
+            max_points(config.payment_weight).saturating_sub(breakdown.payment_points),
+        );
+        self.push(
+            ReasonCode::HighUtilization,
+            max_points(config.utilization_weight).saturating_sub(breakdown.utilization_points),
+        );
+        self.push(
+            ReasonCode::ShortCreditHistory,
+            max_points(config.age_weight).saturating_sub(breakdown.age_points),
+        );
+        self.push(
+            ReasonCode::Delinquencies,
+            max_points(config.delinquency_weight).saturating_sub(breakdown.delinquency_points),
+        );
+        self.push(ReasonCode::FraudReports, breakdown.penalties.fraud_penalty);
+        self.push(ReasonCode::ElevatedRisk, breakdown.penalties.risk_penalty);
+        self
+    }
+
+    /// Add the shortfall of each DEX signal as scored by `aggregator`
+    pub fn with_signals(
+        mut self,
+        aggregator: &SignalAggregator,
+        signals: &Vec<ScoringSignal>,
+    ) -> Self {
+        let shortfalls = aggregator.point_shortfalls(signals);
+        for (signal, points) in signals.iter().zip(shortfalls.iter()) {
+            self.push(ReasonCode::from_signal(&signal.signal_type), points);
+        }
+        self
+    }
+
+    /// The `limit` largest shortfalls, largest first. Factors without a shortfall are omitted.
+    pub fn top(&self, limit: u32) -> Vec<ScoreReason> {
+        let mut ranked: Vec<(ReasonCode, u32)> = Vec::new(self.env);
+        for (code, points) in self.candidates.iter() {
+            let position = ranked
+                .iter()
+                .position(|(_, other)| points > other)
+                .unwrap_or(ranked.len() as usize);
+            ranked.insert(position as u32, (code, points));
+        }
+
+        let mut reasons = Vec::new(self.env);
+        for (code, points) in ranked.iter().take(limit as usize) {
+            reasons.push_back(ScoreReason {
+                code,
+                numeric_code: code.code(),
+                description: String::from_str(self.env, code.description()),
+                points_lost: points,
+            });
+        }
+        reasons
+    }
+
+    /// Codes only, for event payloads
+    pub fn top_codes(&self, limit: u32) -> Vec<ReasonCode> {
+        let mut codes = Vec::new(self.env);
+        for reason in self.top(limit).iter() {
+            codes.push_back(reason.code);
+        }
+        codes
+    }
+
+    /// Signals of the same type (pool and account sources) are merged into one reason
+    fn push(&mut self, code: ReasonCode, points: u32) {
+        if points == 0 {
+            return;
+        }
+        for i in 0..self.candidates.len() {
+            let (existing, total) = self.candidates.get(i).unwrap();
+            if existing == code {
+                self.candidates.set(i, (code, total + points));
+                return;
+            }
+        }
+        self.candidates.push_back((code, points));
+    }
+}
@@ -0,0 +1,235 @@
+//! # Factor-based Scoring Engine
+//!
+//! Turns the factor blob written by `update_factors` into a credit score.
+//!
+//! Factors are stored as a run-length compressed `key=value` list separated
+//! by `;`, for example `ph=96;ut=28;age=54;dlq=1`:
+//!
+//! - `ph`  - percentage of payments made on time (0-100)
+//! - `ut`  - credit utilization percentage (values above 100 are capped)
+//! - `age` - account age in months
+//! - `dlq` - number of recorded delinquencies
+//!
+//! Each factor is normalized to a 0-100 sub-score, weighted by the admin
+//! configured [`ScoringConfig`] and mapped onto `min_score..=max_score`.
+
+use common_utils::compression::{CompressionManager, CompressionType};
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, Bytes, Env};
+
+use crate::penalties::AppliedPenalties;
+
+/// Upper bound for any score produced by the contract
+pub const MAX_SCORE: u32 = 1000;
+
+/// Typed credit factors parsed from the stored factor blob
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditFactors {
+    pub payment_history: u32,
+    pub utilization: u32,
+    pub account_age_months: u32,
+    pub delinquency_count: u32,
+}
+
+/// Admin-configurable weights and caps
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoringConfig {
+    pub payment_weight: u32,
+    pub utilization_weight: u32,
+    pub age_weight: u32,
+    pub delinquency_weight: u32,
+    /// Account age (months) at which the age factor is maxed out
+    pub age_cap_months: u32,
+    /// Delinquency count at which the delinquency factor bottoms out
+    pub delinquency_cap: u32,
+    pub min_score: u32,
+    pub max_score: u32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            payment_weight: 35,
+            utilization_weight: 30,
+            age_weight: 15,
+            delinquency_weight: 20,
+            age_cap_months: 120,
+            delinquency_cap: 5,
+            min_score: 0,
+            max_score: MAX_SCORE,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Sum of the factor weights; `None` if it overflows
+    pub fn total_weight(&self) -> Option<u32> {
+        self.payment_weight
+            .checked_add(self.utilization_weight)?
+            .checked_add(self.age_weight)?
+            .checked_add(self.delinquency_weight)
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.total_weight() != Some(100) {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        if self.age_cap_months == 0 || self.delinquency_cap == 0 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        if self.min_score >= self.max_score || self.max_score > MAX_SCORE {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+}
+
+/// Per-factor contribution to a computed score, in score points
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown {
+    pub factors: CreditFactors,
+    pub payment_points: u32,
+    pub utilization_points: u32,
+    pub age_points: u32,
+    pub delinquency_points: u32,
+    /// Deductions from fraud-detect and risk-eval, already reflected in `score`
+    pub penalties: AppliedPenalties,
+    pub score: u32,
+    pub computed_at: u64,
+}
+
+impl ScoreBreakdown {
+    /// Deduct external penalties from the score, never going below `min_score`
+    pub fn apply_penalties(&mut self, penalties: AppliedPenalties, min_score: u32) {
+        self.score = self.score.saturating_sub(penalties.total()).max(min_score);
+        self.penalties = penalties;
+    }
+}
+
+pub struct ScoringEngine<'a> {
+    env: &'a Env,
+    config: ScoringConfig,
+}
+
+impl<'a> ScoringEngine<'a> {
+    pub fn new(env: &'a Env, config: ScoringConfig) -> Self {
+        Self { env, config }
+    }
+
+    /// Decompress and parse a factor blob stored under `DataKey::Factors`
+    pub fn decode_factors(&self, compressed: &Bytes) -> Result<CreditFactors, ContractError> {
+        let raw = CompressionManager::decompress(compressed, &CompressionType::RunLength)?;
+        Self::parse_factors(&raw)
+    }
+
+    /// Parse a `key=value;key=value` factor list. All four factors are required.
+    pub fn parse_factors(raw: &Bytes) -> Result<CreditFactors, ContractError> {
+        let mut payment_history: Option<u32> = None;
+        let mut utilization: Option<u32> = None;
+        let mut account_age_months: Option<u32> = None;
+        let mut delinquency_count: Option<u32> = None;
+
+        let mut key = [0u8; 4];
+        let mut key_len = 0usize;
+        let mut value: Option<u32> = None;
+
+        // A trailing separator flushes the last pair
+        let len = raw.len();
+        for i in 0..=len {
+            let byte = if i < len { raw.get(i).unwrap() } else { b';' };
+            match byte {
+                b'=' => {
+                    if key_len == 0 || value.is_some() {
+                        return Err(ContractError::InvalidInput);
+                    }
+                    value = Some(0);
+                }
+                b';' | b',' => {
+                    if key_len == 0 && value.is_none() {
+                        continue;
+                    }
+                    let parsed = value.ok_or(ContractError::InvalidInput)?;
+                    let slot = match &key[..key_len] {
+                        b"ph" => &mut payment_history,
+                        b"ut" => &mut utilization,
+                        b"age" => &mut account_age_months,
+                        b"dlq" => &mut delinquency_count,
+                        _ => return Err(ContractError::InvalidInput),
+                    };
+                    *slot = Some(parsed);
+                    key_len = 0;
+                    value = None;
+                }
+                b' ' => {}
+                b'0'..=b'9' if value.is_some() => {
+                    let digit = (byte - b'0') as u32;
+                    value = Some(
+                        value
+                            .unwrap()
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit))
+                            .ok_or(ContractError::InvalidInput)?,
+                    );
+                }
+                _ if value.is_none() && key_len < key.len() => {
+                    key[key_len] = byte;
+                    key_len += 1;
+                }
+                _ => return Err(ContractError::InvalidInput),
+            }
+        }
+
+        let factors = CreditFactors {
+            payment_history: payment_history.ok_or(ContractError::InvalidInput)?,
+            utilization: utilization.ok_or(ContractError::InvalidInput)?,
+            account_age_months: account_age_months.ok_or(ContractError::InvalidInput)?,
+            delinquency_count: delinquency_count.ok_or(ContractError::InvalidInput)?,
+        };
+
+        if factors.payment_history > 100 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        Ok(factors)
+    }
+
+    /// Weight the normalized factors and map them onto the configured score range
+    pub fn score(&self, factors: &CreditFactors) -> ScoreBreakdown {
+        let cfg = &self.config;
+
+        let payment = factors.payment_history.min(100);
+        let utilization = 100 - factors.utilization.min(100);
+        let age = factors.account_age_months.min(cfg.age_cap_months) * 100 / cfg.age_cap_months;
+        let delinquency =
+            100 - factors.delinquency_count.min(cfg.delinquency_cap) * 100 / cfg.delinquency_cap;
+
+        let payment_points = self.points(payment, cfg.payment_weight);
+        let utilization_points = self.points(utilization, cfg.utilization_weight);
+        let age_points = self.points(age, cfg.age_weight);
+        let delinquency_points = self.points(delinquency, cfg.delinquency_weight);
+
+        let score =
+            (cfg.min_score + payment_points + utilization_points + age_points + delinquency_points)
+                .min(cfg.max_score);
+
+        ScoreBreakdown {
+            factors: factors.clone(),
+            payment_points,
+            utilization_points,
+            age_points,
+            delinquency_points,
+            penalties: AppliedPenalties::none(),
+            score,
+            computed_at: self.env.ledger().timestamp(),
+        }
+    }
+
+    /// Convert a 0-100 sub-score into score points for the given weight
+    fn points(&self, sub_score: u32, weight: u32) -> u32 {
+        let range = (self.config.max_score - self.config.min_score) as u64;
+        (sub_score as u64 * weight as u64 * range / 10_000) as u32
+    }
+}
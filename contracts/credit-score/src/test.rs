@@ -0,0 +1,867 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, Address, Bytes, Env, String, Symbol};
+
+fn setup(env: &Env) -> (CreditScoreContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, CreditScoreContract);
+    let client = CreditScoreContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    (client, admin)
+}
+
+#[test]
+fn test_calculate_score_from_factors() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let account = Address::generate(&env);
+
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=100;ut=0;age=120;dlq=0"),
+    );
+    assert_eq!(client.calculate_score(&account), 1000);
+    assert_eq!(client.get_score(&account, &account), 1000);
+
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=80;ut=50;age=60;dlq=5"),
+    );
+    let score = client.calculate_score(&account);
+
+    // 35% * 80 + 30% * 50 + 15% * 50 + 20% * 0, scaled to 0..1000
+    assert_eq!(score, 505);
+
+    let breakdown = client.get_score_breakdown(&account, &account).unwrap();
+    assert_eq!(breakdown.score, score);
+    assert_eq!(breakdown.delinquency_points, 0);
+    assert_eq!(breakdown.factors.utilization, 50);
+}
+
+#[test]
+fn test_scoring_config_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    let mut config = ScoringConfig::default();
+    config.payment_weight = 50;
+    assert!(client.try_set_scoring_config(&admin, &config).is_err());
+
+    // Weights that would wrap around to 100 are rejected, not a panic
+    let mut config = ScoringConfig::default();
+    config.payment_weight = u32::MAX;
+    config.utilization_weight = 101;
+    config.age_weight = 0;
+    config.delinquency_weight = 0;
+    assert_eq!(
+        client.try_set_scoring_config(&admin, &config),
+        Err(Ok(ContractError::InvalidConfiguration))
+    );
+
+    let mut config = ScoringConfig::default();
+    config.payment_weight = 55;
+    config.utilization_weight = 10;
+    client.set_scoring_config(&admin, &config);
+    assert_eq!(client.get_scoring_config(), config);
+}
+
+#[test]
+fn test_calculate_score_without_factors_fails() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert!(client
+        .try_calculate_score(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_parse_factors_rejects_malformed_input() {
+    let env = Env::default();
+
+    let ok = Bytes::from_slice(&env, b"ph=96; ut=28; age=54; dlq=1");
+    let factors = ScoringEngine::parse_factors(&ok).unwrap();
+    assert_eq!(factors.account_age_months, 54);
+
+    for raw in [
+        &b"ph=96;ut=28;age=54"[..],
+        &b"ph=101;ut=28;age=54;dlq=1"[..],
+        &b"ph=96;ut=28;age=54;dlq=1;xyz=3"[..],
+        &b"ph96;ut=28;age=54;dlq=1"[..],
+    ] {
+        assert!(ScoringEngine::parse_factors(&Bytes::from_slice(&env, raw)).is_err());
+    }
+}
+
+#[test]
+fn test_dispute_freezes_and_corrects_score() {
+    use common_utils::acl::{ACLContract, ACLContractClient, Role};
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+
+    let acl_id = env.register_contract(None, ACLContract);
+    let acl = ACLContractClient::new(&env, &acl_id);
+    acl.initialize(&admin);
+    acl.grant_role(&admin, &reviewer, &Role::Reviewer, &None);
+    acl.grant_permission(
+        &admin,
+        &Role::Reviewer,
+        &symbol_short!("score"),
+        &symbol_short!("resolve"),
+    );
+    client.set_acl_contract(&admin, &acl_id);
+
+    client.set_score(&account, &420);
+
+    let evidence = Bytes::from_slice(&env, b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    let dispute_id = client.open_dispute(&account, &evidence);
+
+    // Score is frozen and a second dispute cannot be opened
+    assert!(client.try_set_score(&account, &300).is_err());
+    assert!(client.try_open_dispute(&account, &evidence).is_err());
+
+    // Only reviewers may resolve
+    let outsider = Address::generate(&env);
+    assert!(client
+        .try_resolve_dispute(&outsider, &dispute_id, &Some(610))
+        .is_err());
+
+    let status = client.resolve_dispute(&reviewer, &dispute_id, &Some(610));
+    assert_eq!(status, DisputeStatus::Corrected);
+    assert_eq!(client.get_score(&account, &account), 610);
+    assert!(client.get_open_dispute(&account).is_none());
+
+    let audit = client.get_dispute_audit(&dispute_id);
+    assert_eq!(audit.len(), 2);
+    assert_eq!(audit.get(1).unwrap().action, DisputeAction::Corrected);
+    assert_eq!(audit.get(1).unwrap().score_before, 420);
+
+    // Unfrozen after resolution
+    client.set_score(&account, &640);
+}
+
+#[test]
+fn test_consent_gates_score_reads() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let owner = Address::generate(&env);
+    let lender = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_score(&owner, &700);
+
+    assert!(client.try_get_score(&lender, &owner).is_err());
+
+    client.grant_consent(&owner, &lender, &symbol_short!("mortgage"), &2_000);
+    assert_eq!(client.get_score(&lender, &owner), 700);
+    assert_eq!(client.list_consents(&owner).len(), 1);
+
+    let log = client.get_access_log(&owner);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.get(0).unwrap().accessor, lender);
+    assert_eq!(log.get(0).unwrap().purpose, Some(symbol_short!("mortgage")));
+
+    // Owner reads are not logged
+    client.get_score(&owner, &owner);
+    assert_eq!(client.get_access_log(&owner).len(), 1);
+
+    // Expired consent no longer grants access
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(client.try_get_score(&lender, &owner).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.revoke_consent(&owner, &lender);
+    assert!(client.try_get_score(&lender, &owner).is_err());
+    assert_eq!(client.list_consents(&owner).len(), 0);
+}
+
+#[test]
+fn test_indexer_records_account_trades() {
+    use common_utils::dex::account_activity::AccountTrade;
+    use common_utils::dex::TokenPair;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let indexer = Address::generate(&env);
+    let account = Address::generate(&env);
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+
+    let trade = |amount_usd: i128, slippage_bps: u32, holding: u64, timestamp: u64| AccountTrade {
+        pair: pair.clone(),
+        is_buy: holding == 0,
+        amount_usd,
+        slippage_bps,
+        holding_period_seconds: holding,
+        timestamp,
+    };
+
+    let mut trades = Vec::new(&env);
+    trades.push_back(trade(1_000, 20, 0, 100));
+    trades.push_back(trade(3_000, 40, 600, 700));
+
+    // Unregistered indexers are rejected
+    assert!(client
+        .try_record_account_trades(&indexer, &account, &trades)
+        .is_err());
+
+    client.add_indexer(&admin, &indexer);
+    assert_eq!(client.record_account_trades(&indexer, &account, &trades), 2);
+
+    let ledger = client.get_account_ledger(&account).unwrap();
+    assert_eq!(ledger.total_volume_usd, 4_000);
+    assert_eq!(ledger.avg_slippage_bps(), 30);
+    assert_eq!(ledger.avg_holding_period_seconds(), 600);
+    assert_eq!(ledger.active_period_seconds(), 600);
+
+    let mut invalid = Vec::new(&env);
+    invalid.push_back(trade(0, 20, 0, 800));
+    assert!(client
+        .try_record_account_trades(&indexer, &account, &invalid)
+        .is_err());
+}
+
+#[test]
+fn test_signal_weights_are_versioned() {
+    use common_utils::dex::scoring_signals::{SignalType, SignalWeight};
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert_eq!(client.get_signal_weights(&None).unwrap().version, 0);
+
+    let mut weights = Vec::new(&env);
+    weights
+        .push_back(SignalWeight::new(SignalType::TradingVolume, 60, 0, 1_000_000).with_decay(500));
+    weights.push_back(SignalWeight::new(SignalType::PriceStability, 40, 0, 10000));
+    assert_eq!(client.update_signal_weights(&admin, &weights), 1);
+
+    let current = client.get_signal_weights(&None).unwrap();
+    assert_eq!(current.version, 1);
+    assert_eq!(current.weights, weights);
+    assert_eq!(
+        client.get_signal_weights(&Some(0)).unwrap().weights.len(),
+        6
+    );
+    assert!(client.get_signal_weights(&Some(2)).is_none());
+
+    let mut invalid = Vec::new(&env);
+    invalid.push_back(
+        SignalWeight::new(SignalType::TradingVolume, 60, 0, 1_000_000).with_decay(20_000),
+    );
+    assert!(client.try_update_signal_weights(&admin, &invalid).is_err());
+
+    let mut duplicate = Vec::new(&env);
+    duplicate.push_back(SignalWeight::new(SignalType::TradingVolume, 30, 0, 1_000_000));
+    duplicate.push_back(SignalWeight::new(SignalType::TradingVolume, 30, 0, 1_000_000));
+    assert_eq!(
+        client.try_update_signal_weights(&admin, &duplicate),
+        Err(Ok(ContractError::InvalidConfiguration))
+    );
+
+    let mut overflowing = Vec::new(&env);
+    overflowing.push_back(SignalWeight::new(SignalType::TradingVolume, u32::MAX, 0, 1_000_000));
+    overflowing.push_back(SignalWeight::new(SignalType::PriceStability, 1, 0, 10000));
+    assert_eq!(
+        client.try_update_signal_weights(&admin, &overflowing),
+        Err(Ok(ContractError::InvalidConfiguration))
+    );
+}
+
+#[test]
+fn test_score_reasons_rank_largest_shortfalls() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let account = Address::generate(&env);
+
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=80;ut=50;age=60;dlq=5"),
+    );
+    client.calculate_score(&account);
+
+    // Lost points: delinquency 200, utilization 150, age 75, payments 70
+    let reasons = client.get_score_reasons(&account, &account, &2);
+    assert_eq!(reasons.len(), 2);
+    assert_eq!(reasons.get(0).unwrap().code, ReasonCode::Delinquencies);
+    assert_eq!(reasons.get(0).unwrap().numeric_code, 104);
+    assert_eq!(reasons.get(0).unwrap().points_lost, 200);
+    assert_eq!(reasons.get(1).unwrap().code, ReasonCode::HighUtilization);
+
+    let with_signals = client.get_score_with_signals(&account, &account);
+    assert_eq!(with_signals.reasons.len(), DEFAULT_REASON_COUNT);
+    assert_eq!(
+        with_signals.reasons.get(3).unwrap().code,
+        ReasonCode::LatePayments
+    );
+
+    // A perfect score has nothing to explain
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=100;ut=0;age=120;dlq=0"),
+    );
+    client.calculate_score(&account);
+    assert!(client.get_score_reasons(&account, &account, &4).is_empty());
+
+    // A score set without factors has nothing to explain either
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=80;ut=50;age=60;dlq=5"),
+    );
+    client.calculate_score(&account);
+    assert!(!client.get_score_reasons(&account, &account, &4).is_empty());
+    client.set_score(&account, &640);
+    assert!(client.get_score_reasons(&account, &account, &4).is_empty());
+    assert_eq!(client.get_score_breakdown(&account, &account), None);
+}
+
+#[test]
+fn test_score_bands_track_transitions() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.set_score(&alice, &600);
+    client.set_score(&bob, &820);
+    assert_eq!(
+        client.get_score_band(&alice, &alice).name,
+        symbol_short!("fair")
+    );
+
+    let counts = client.get_band_counts();
+    assert_eq!(counts.get(1).unwrap().accounts, 1);
+    assert_eq!(counts.get(3).unwrap().accounts, 1);
+
+    // Moving across a boundary updates both bands
+    client.set_score(&alice, &700);
+    let counts = client.get_band_counts();
+    assert_eq!(counts.get(1).unwrap().accounts, 0);
+    assert_eq!(counts.get(2).unwrap().accounts, 1);
+
+    let mut bands = Vec::new(&env);
+    bands.push_back(ScoreBand {
+        name: symbol_short!("low"),
+        min_score: 100,
+    });
+    assert!(client.try_set_score_bands(&admin, &bands).is_err());
+
+    let mut bands = Vec::new(&env);
+    bands.push_back(ScoreBand {
+        name: symbol_short!("low"),
+        min_score: 0,
+    });
+    bands.push_back(ScoreBand {
+        name: symbol_short!("high"),
+        min_score: 500,
+    });
+    client.set_score_bands(&admin, &bands);
+    assert_eq!(
+        client.get_score_band(&bob, &bob).name,
+        symbol_short!("high")
+    );
+    // New bands are counted from the score buckets
+    let counts = client.get_band_counts();
+    assert_eq!(counts.get(0).unwrap().accounts, 0);
+    assert_eq!(counts.get(1).unwrap().accounts, 2);
+
+    // Bounds must fall on a bucket boundary
+    bands.set(
+        1,
+        ScoreBand {
+            name: symbol_short!("high"),
+            min_score: 505,
+        },
+    );
+    assert!(client.try_set_score_bands(&admin, &bands).is_err());
+}
+
+#[test]
+fn test_score_band_ignores_decay() {
+    use common_utils::storage_optimization::DecayModel;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+
+    client.set_score(&account, &820);
+    client.set_decay_config(
+        &admin,
+        &DecayConfig {
+            model: DecayModel::Linear,
+            neutral_score: 500,
+            period_seconds: 1_000,
+            grace_period_seconds: 0,
+            stale_after_seconds: 10_000,
+        },
+    );
+    env.ledger().with_mut(|li| li.timestamp += 500);
+
+    // The decayed score has left the band, but band and counts both use the stored score
+    assert!(client.get_score(&account, &account) < 820);
+    let band = client.get_score_band(&account, &account);
+    let counts = client.get_band_counts();
+    let index = counts.iter().position(|count| count.band == band).unwrap() as u32;
+    assert_eq!(counts.get(index).unwrap().accounts, 1);
+    assert_eq!(band.min_score, 800);
+}
+
+#[test]
+fn test_scored_account_index_pagination() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    let mut accounts = Vec::new(&env);
+    for i in 0..5u32 {
+        let account = Address::generate(&env);
+        client.set_score(&account, &(500 + i * 10));
+        accounts.push_back(account);
+    }
+
+    // Rescoring an account does not index it twice
+    client.set_score(&accounts.get(0).unwrap(), &700);
+    assert_eq!(client.get_scored_account_count(), 5);
+
+    let first = client.get_scored_accounts(&admin, &0, &3);
+    assert_eq!(first.accounts.len(), 3);
+    assert_eq!(first.next_cursor, Some(3));
+    assert_eq!(first.total, 5);
+
+    let second = client.get_scored_accounts(&admin, &3, &3);
+    assert_eq!(second.accounts.len(), 2);
+    assert_eq!(second.next_cursor, None);
+
+    client.delete_score(&admin, &accounts.get(1).unwrap());
+    assert_eq!(client.get_scored_account_count(), 4);
+    assert!(client
+        .try_get_score(&accounts.get(1).unwrap(), &accounts.get(1).unwrap())
+        .is_err());
+
+    let all = client.get_scored_accounts(&admin, &0, &10);
+    assert!(!all.accounts.contains(&accounts.get(1).unwrap()));
+    assert!(all.accounts.contains(&accounts.get(4).unwrap()));
+}
+
+#[test]
+fn test_rescore_accounts_from_index() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let with_factors = Address::generate(&env);
+    let without_factors = Address::generate(&env);
+
+    client.set_score(&with_factors, &300);
+    client.set_score(&without_factors, &400);
+    client.update_factors(
+        &with_factors,
+        &String::from_str(&env, "ph=100;ut=0;age=120;dlq=0"),
+    );
+
+    let result = client.rescore_accounts(&admin, &0, &10);
+    assert_eq!(result.rescored, 1);
+    assert_eq!(result.skipped, 1);
+    assert_eq!(result.next_cursor, None);
+    assert_eq!(client.get_score(&with_factors, &with_factors), 1000);
+}
+
+#[soroban_sdk::contract]
+struct MockFraudContract;
+
+#[soroban_sdk::contractimpl]
+impl MockFraudContract {
+    pub fn get_latest_score(_env: Env, _agent_id: Symbol) -> u32 {
+        80
+    }
+}
+
+#[soroban_sdk::contract]
+struct MockRiskContract;
+
+#[soroban_sdk::contractimpl]
+impl MockRiskContract {
+    pub fn get_risk(_env: Env, _agent: Address) -> Option<RiskLevel> {
+        Some(RiskLevel::Critical)
+    }
+}
+
+#[test]
+fn test_external_risk_penalties_reduce_score() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+
+    let fraud_id = env.register_contract(None, MockFraudContract);
+    let risk_id = env.register_contract(None, MockRiskContract);
+    client.set_risk_contracts(&admin, &Some(fraud_id), &Some(risk_id));
+
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=100;ut=0;age=120;dlq=0"),
+    );
+
+    // Not linked to a fraud agent: only the Critical risk penalty applies
+    assert_eq!(client.calculate_score(&account), 600);
+
+    client.link_fraud_agent(&admin, &account, &symbol_short!("agent1"));
+    // 1000 - 400 (critical) - 200 * 80 / 100 (fraud)
+    assert_eq!(client.calculate_score(&account), 440);
+
+    let breakdown = client.get_score_breakdown(&account, &account).unwrap();
+    assert_eq!(breakdown.penalties.fraud_score, Some(80));
+    assert_eq!(breakdown.penalties.risk_level, Some(RiskLevel::Critical));
+    assert_eq!(breakdown.penalties.fraud_penalty, 160);
+    assert_eq!(breakdown.penalties.risk_penalty, 400);
+
+    let reasons = client.get_score_reasons(&account, &account, &1);
+    assert_eq!(reasons.get(0).unwrap().code, ReasonCode::ElevatedRisk);
+
+    let mut config = PenaltyConfig::default();
+    config.critical_risk_penalty = 2_000;
+    assert!(client.try_set_penalty_config(&admin, &config).is_err());
+}
+
+#[test]
+fn test_failed_penalty_queries_are_skipped() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+
+    // Neither contract exposes the queried functions
+    let broken_id = env.register_contract(None, MockRiskContract);
+    let fraud_id = env.register_contract(None, MockFraudContract);
+    client.set_risk_contracts(&admin, &Some(broken_id.clone()), &Some(fraud_id));
+    client.link_fraud_agent(&admin, &account, &symbol_short!("agent1"));
+    client.update_factors(
+        &account,
+        &String::from_str(&env, "ph=100;ut=0;age=120;dlq=0"),
+    );
+
+    assert_eq!(client.calculate_score(&account), 1000);
+    let breakdown = client.get_score_breakdown(&account, &account).unwrap();
+    assert_eq!(breakdown.penalties, AppliedPenalties::none());
+}
+
+#[test]
+fn test_submit_score_rejects_invalid_attestations() {
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    let key = BytesN::from_array(&env, &[7u8; 32]);
+    let signature = BytesN::from_array(&env, &[0u8; 64]);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let attestation = ScoreAttestation {
+        account: account.clone(),
+        score: 720,
+        model_version: 3,
+        nonce: 1,
+        expires_at: 2_000,
+    };
+
+    // Unregistered key
+    assert!(client
+        .try_submit_score(&attestation, &key, &signature)
+        .is_err());
+
+    client.register_oracle_key(&admin, &key);
+    assert!(client.get_oracle_key(&key).is_some());
+
+    // Expired
+    let mut expired = attestation.clone();
+    expired.expires_at = 1_000;
+    assert!(client.try_submit_score(&expired, &key, &signature).is_err());
+
+    // Out of range
+    let mut too_high = attestation.clone();
+    too_high.score = MAX_SCORE + 1;
+    assert!(client
+        .try_submit_score(&too_high, &key, &signature)
+        .is_err());
+
+    // Bad signature
+    assert!(client
+        .try_submit_score(&attestation, &key, &signature)
+        .is_err());
+    assert!(!client.is_nonce_used(&key, &1));
+    assert!(client.try_get_score(&account, &account).is_err());
+
+    client.revoke_oracle_key(&admin, &key);
+    assert!(client.get_oracle_key(&key).is_none());
+}
+
+#[test]
+fn test_submit_score_accepts_signed_attestation_once() {
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::xdr::ToXdr;
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let key = BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.register_oracle_key(&admin, &key);
+
+    let attestation = ScoreAttestation {
+        account: account.clone(),
+        score: 720,
+        model_version: 3,
+        nonce: 1,
+        expires_at: 2_000,
+    };
+    let payload = (client.address.clone(), attestation.clone()).to_xdr(&env);
+    let mut message = [0u8; 512];
+    let message = &mut message[..payload.len() as usize];
+    payload.copy_into_slice(message);
+    let signature = BytesN::from_array(&env, &signer.sign(message).to_bytes());
+
+    client.submit_score(&attestation, &key, &signature);
+
+    assert_eq!(client.get_score(&account, &account), 720);
+    assert!(client.is_nonce_used(&key, &1));
+    let provenance = client.get_score_provenance(&account, &account).unwrap();
+    assert_eq!(provenance.public_key, key);
+    assert_eq!(provenance.model_version, 3);
+    assert_eq!(provenance.nonce, 1);
+    assert_eq!(provenance.submitted_at, 1_000);
+
+    // Replaying the same attestation is rejected
+    assert_eq!(
+        client.try_submit_score(&attestation, &key, &signature),
+        Err(Ok(ContractError::InvalidState))
+    );
+}
+
+#[test]
+fn test_score_history_range_and_trend() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let account = Address::generate(&env);
+    let day = 86_400u64;
+
+    // One score per day rising by 10 points: 600, 610, ..., 650
+    for i in 0..6u64 {
+        env.ledger()
+            .with_mut(|li| li.timestamp = 100 * day + i * day);
+        client.set_score(&account, &(600 + i as u32 * 10));
+    }
+
+    let page =
+        client.get_score_history_range(&account, &account, &(101 * day), &(104 * day), &0, &3);
+    assert_eq!(page.entries.len(), 3);
+    assert_eq!(page.entries.get(0).unwrap().score, 610);
+    assert_eq!(page.next_cursor, Some(3));
+
+    let page =
+        client.get_score_history_range(&account, &account, &(101 * day), &(104 * day), &3, &3);
+    assert_eq!(page.entries.len(), 1);
+    assert_eq!(page.entries.get(0).unwrap().score, 640);
+    assert_eq!(page.next_cursor, None);
+
+    let trend = client.get_score_trend(&account, &account, &(90 * day));
+    assert_eq!(trend.count, 6);
+    assert_eq!(trend.min, 600);
+    assert_eq!(trend.max, 650);
+    assert_eq!(trend.mean, 625);
+    assert_eq!(trend.slope_milli_per_day, 10_000);
+    // Population std dev of 600..=650 step 10 is ~17.08
+    assert_eq!(trend.volatility, 17);
+
+    assert!(client.try_get_score_trend(&account, &account, &0).is_ok());
+    assert!(client
+        .try_get_score_history_range(&account, &account, &10, &5, &0, &10)
+        .is_err());
+}
+
+#[test]
+fn test_bulk_score_import() {
+    use common_utils::batch::{OperationStatus, RollbackStrategy};
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let row = |account: &Address, score: u32, timestamp: u64| ScoreImportRow {
+        account: account.clone(),
+        score,
+        timestamp,
+    };
+
+    // Partial: valid rows land, invalid rows are reported
+    let job = client.start_score_import(&admin, &2, &RollbackStrategy::Partial);
+    let mut rows = Vec::new(&env);
+    rows.push_back(row(&a, 640, 9_000));
+    rows.push_back(row(&b, 1_200, 9_000));
+    rows.push_back(row(&c, 700, 20_000));
+    let result = client.import_score_chunk(&admin, &job, &0, &rows);
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(result.failed, 2);
+    assert_eq!(
+        result.results.get(1).unwrap().status,
+        OperationStatus::Failed
+    );
+    assert_eq!(result.results.get(1).unwrap().error_code, 1);
+    assert_eq!(client.get_score(&a, &a), 640);
+
+    // Already applied chunk indexes are rejected
+    assert!(client
+        .try_import_score_chunk(&admin, &job, &0, &rows)
+        .is_err());
+
+    // An older row than the stored score is skipped
+    let mut rows = Vec::new(&env);
+    rows.push_back(row(&a, 300, 8_000));
+    let result = client.import_score_chunk(&admin, &job, &1, &rows);
+    assert_eq!(result.skipped, 1);
+    assert_eq!(client.get_score(&a, &a), 640);
+
+    let job_state = client.get_score_import(&job).unwrap();
+    assert!(job_state.completed_at.is_some());
+    assert_eq!(job_state.imported, 1);
+
+    // AllOrNothing: one bad row rejects the chunk, which can then be resent
+    let job = client.start_score_import(&admin, &1, &RollbackStrategy::AllOrNothing);
+    let mut rows = Vec::new(&env);
+    rows.push_back(row(&b, 550, 9_500));
+    rows.push_back(row(&c, 1_500, 9_500));
+    let result = client.import_score_chunk(&admin, &job, &0, &rows);
+    assert!(result.rolled_back);
+    assert_eq!(
+        result.results.get(0).unwrap().status,
+        OperationStatus::RolledBack
+    );
+    assert!(client.try_get_score(&b, &b).is_err());
+
+    rows.set(1, row(&c, 500, 9_500));
+    let result = client.import_score_chunk(&admin, &job, &0, &rows);
+    assert_eq!(result.succeeded, 2);
+    assert_eq!(client.get_score(&b, &b), 550);
+}
+
+#[test]
+fn test_pause_blocks_writes_but_not_reads() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    let lender = Address::generate(&env);
+
+    client.set_score(&account, &650);
+    client.grant_consent(&account, &lender, &symbol_short!("loan"), &u64::MAX);
+
+    client.pause(&admin);
+    assert!(client.try_set_score(&account, &700).is_err());
+    assert!(client.try_pause(&admin).is_err());
+    assert_eq!(
+        client.try_calculate_score_multi_pair(&account),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(
+        client.try_batch_calculate_scores_multi_pair(&vec![&env, account.clone()]),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(client.get_score(&account, &account), 650);
+
+    // Owners can still withdraw consent while paused
+    client.revoke_consent(&account, &lender);
+
+    client.resume(&admin);
+    client.set_score(&account, &700);
+
+    client.terminate(&admin);
+    assert!(client.try_set_score(&account, &710).is_err());
+    assert!(client.try_resume(&admin).is_err());
+    assert_eq!(client.get_score(&account, &account), 700);
+}
+
+#[soroban_sdk::contract]
+struct MockWatchlist;
+
+#[soroban_sdk::contractimpl]
+impl MockWatchlist {
+    pub fn block(env: Env, address: Address) {
+        env.storage().instance().set(&address, &true);
+    }
+
+    pub fn is_blocked(env: Env, address: Address) -> bool {
+        env.storage().instance().get(&address).unwrap_or(false)
+    }
+}
+
+#[test]
+fn test_watchlist_rejects_blocked_callers() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    let lender = Address::generate(&env);
+
+    client.set_score(&account, &650);
+    client.grant_consent(&account, &lender, &symbol_short!("loan"), &u64::MAX);
+
+    let watchlist_id = env.register_contract(None, MockWatchlist);
+    MockWatchlistClient::new(&env, &watchlist_id).block(&lender);
+    client.set_watchlist(&admin, &Some(watchlist_id.clone()));
+
+    assert_eq!(
+        client.try_get_score_band(&lender, &account),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(client.try_get_score(&lender, &account).is_err());
+    assert_eq!(client.get_score(&account, &account), 650);
+
+    client.set_watchlist(&admin, &None);
+    assert_eq!(client.get_score(&lender, &account), 650);
+}
+
+#[test]
+fn test_fraud_contract_freezes_score() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    client.set_score(&account, &650);
+
+    // No fraud contract configured yet
+    assert_eq!(
+        client.try_freeze_score(&account),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    let fraud_id = env.register_contract(None, MockFraudContract);
+    client.set_risk_contracts(&admin, &Some(fraud_id), &None);
+    client.freeze_score(&account);
+    assert!(client.is_score_frozen(&account));
+    assert!(client.try_set_score(&account, &700).is_err());
+
+    client.unfreeze_score(&admin, &account);
+    assert!(!client.is_score_frozen(&account));
+    client.set_score(&account, &700);
+    assert_eq!(client.get_score(&account, &account), 700);
+}
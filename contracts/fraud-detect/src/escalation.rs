@@ -0,0 +1,164 @@
+//! # Critical Risk Escalation
+//!
+//! Actions run when an analysis rates a pair `RiskLevel::Critical`. Every
+//! critical `analyze_trading_for_fraud` run emits the policy's `Alert` steps,
+//! which change nothing but their cooldown. The other steps run only when a
+//! caller holding the `("fraud", "escalate")` permission calls `escalate`,
+//! so an arbitrary caller cannot trigger proposals or freezes.
+//!
+//! The admin configures a policy per pair, or a default policy for pairs
+//! without one. Each step has its own cooldown, so a pair that stays critical
+//! across escalations does not repeat the step until the cooldown has passed.
+//! A step whose cross-contract call fails does not start its cooldown and is
+//! retried on the next critical escalation.
+//!
+//! - `Alert` - publishes an [`EscalationAlert`] event.
+//! - `MultisigProposal` - opens a critical proposal on a `MultiSignatureContract`
+//!   for the configured call; the signers still approve and execute it.
+//! - `TimelockOperation` - schedules the call on a `TimeLock`. This contract
+//!   must be the timelock admin.
+//! - `FreezeScore` - freezes the scores of the traders the pair's trade
+//!   stream implicates (see `TradeStream::flagged_traders`) on the
+//!   credit-score contract, which must have this contract configured as its
+//!   fraud source. The step is skipped while no trader is implicated.
+
+use common_utils::dex::fraud_indicators::RiskLevel;
+use common_utils::dex::TokenPair;
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol, Val, Vec};
+
+pub const MAX_ESCALATION_STEPS: u32 = 10;
+
+/// `OperationType::Critical` in the multisig contract
+const MULTISIG_CRITICAL_OPERATION: u32 = 3;
+
+/// A call made through the multisig or timelock contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCall {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscalationAction {
+    Alert,
+    /// Multisig contract and the call to propose
+    MultisigProposal(Address, ContractCall),
+    /// Timelock contract, the call to schedule and its `DelayLevel` (1-3)
+    TimelockOperation(Address, ContractCall, u32),
+    /// Credit-score contract on which the flagged traders' scores are frozen
+    FreezeScore(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscalationStep {
+    pub action: EscalationAction,
+    /// Seconds before the step can run again for the same pair
+    pub cooldown: u64,
+}
+
+impl EscalationStep {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let EscalationAction::TimelockOperation(_, _, delay_level) = &self.action {
+            if !(1..=3).contains(delay_level) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the step has anything to act on for `alert`
+    pub fn applies_to(&self, alert: &EscalationAlert) -> bool {
+        match self.action {
+            EscalationAction::FreezeScore(_) => !alert.flagged_accounts.is_empty(),
+            _ => true,
+        }
+    }
+
+    pub fn is_cooling_down(&self, last_run: Option<u64>, now: u64) -> bool {
+        last_run.map_or(false, |last_run| now < last_run.saturating_add(self.cooldown))
+    }
+}
+
+/// Payload of the `esc_alert` event
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscalationAlert {
+    pub pair: TokenPair,
+    pub risk_level: RiskLevel,
+    pub critical_count: u32,
+    pub total_score_impact: i32,
+    pub fired_rules: Vec<u32>,
+    /// Traders the trade stream implicates
+    pub flagged_accounts: Vec<Address>,
+    pub analyzed_at: u64,
+}
+
+/// Run `action` for `alert`. Returns false when a cross-contract call failed.
+pub fn execute(env: &Env, action: &EscalationAction, alert: &EscalationAlert) -> bool {
+    match action {
+        EscalationAction::Alert => {
+            env.events().publish(
+                (
+                    symbol_short!("esc_alert"),
+                    alert.pair.symbol_a.clone(),
+                    alert.pair.symbol_b.clone(),
+                ),
+                alert.clone(),
+            );
+            true
+        }
+        EscalationAction::MultisigProposal(multisig, call) => try_call(
+            env,
+            multisig,
+            "create_proposal",
+            soroban_sdk::vec![
+                env,
+                MULTISIG_CRITICAL_OPERATION.into_val(env),
+                Some(call.contract.clone()).into_val(env),
+                call.function.into_val(env),
+                call.args.into_val(env),
+                env.current_contract_address().into_val(env),
+            ],
+        ),
+        EscalationAction::TimelockOperation(timelock, call, delay_level) => try_call(
+            env,
+            timelock,
+            "schedule_operation",
+            soroban_sdk::vec![
+                env,
+                call.contract.into_val(env),
+                call.function.into_val(env),
+                call.args.into_val(env),
+                delay_level.into_val(env),
+            ],
+        ),
+        EscalationAction::FreezeScore(credit_score) => {
+            let mut all_frozen = true;
+            for account in alert.flagged_accounts.iter() {
+                all_frozen &= try_call(
+                    env,
+                    credit_score,
+                    "freeze_score",
+                    soroban_sdk::vec![env, account.into_val(env)],
+                );
+            }
+            all_frozen
+        }
+    }
+}
+
+fn try_call(env: &Env, contract: &Address, function: &str, args: Vec<Val>) -> bool {
+    matches!(
+        env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            contract,
+            &Symbol::new(env, function),
+            args,
+        ),
+        Ok(Ok(_))
+    )
+}
@@ -0,0 +1,84 @@
+//! # Analysis History
+//!
+//! Every `analyze_trading_for_fraud` or `escalate` run appends a snapshot of
+//! its indicators and patterns to per-pair time series keyed by the full
+//! `TokenPair`; read paths such as `get_risk_score` analyze without
+//! recording. A second run at the same ledger timestamp replaces that
+//! timestamp's snapshot. Each series keeps the newest [`MAX_SNAPSHOTS`]
+//! timestamps so analysts can follow how a pair's risk evolved without
+//! storage growing without bound.
+
+use common_utils::dex::fraud_indicators::{FraudIndicator, OverallRisk, TradingPattern};
+use soroban_sdk::{contracttype, Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Snapshots retained per pair and series
+pub const MAX_SNAPSHOTS: u32 = 96;
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct IndicatorSnapshot {
+    pub analyzed_at: u64,
+    pub overall_risk: OverallRisk,
+    pub indicators: Vec<FraudIndicator>,
+    pub fired_rules: Vec<u32>,
+    pub threshold_version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PatternSnapshot {
+    pub analyzed_at: u64,
+    pub patterns: Vec<TradingPattern>,
+}
+
+pub trait Snapshot {
+    fn analyzed_at(&self) -> u64;
+}
+
+impl Snapshot for IndicatorSnapshot {
+    fn analyzed_at(&self) -> u64 {
+        self.analyzed_at
+    }
+}
+
+impl Snapshot for PatternSnapshot {
+    fn analyzed_at(&self) -> u64 {
+        self.analyzed_at
+    }
+}
+
+/// Append `snapshot`, dropping the oldest entry once the series is full.
+/// A snapshot taken at the same time as the newest one replaces it.
+pub fn push_bounded<T>(series: &mut Vec<T>, snapshot: T)
+where
+    T: Snapshot + IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if let Some(newest) = series.last() {
+        if newest.analyzed_at() == snapshot.analyzed_at() {
+            series.set(series.len() - 1, snapshot);
+            return;
+        }
+    }
+    if series.len() >= MAX_SNAPSHOTS {
+        series.pop_front();
+    }
+    series.push_back(snapshot);
+}
+
+/// Snapshots taken between `from` and `to`, inclusive, oldest first
+pub fn window<T>(env: &Env, series: &Vec<T>, from: u64, to: u64) -> Vec<T>
+where
+    T: Snapshot + IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut result = Vec::new(env);
+    for snapshot in series.iter() {
+        let at = snapshot.analyzed_at();
+        if at > to {
+            break;
+        }
+        if at >= from {
+            result.push_back(snapshot);
+        }
+    }
+    result
+}
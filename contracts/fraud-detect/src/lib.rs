@@ -15,6 +15,7 @@ use common_utils::dex::fraud_indicators::{
     Severity, TradingPattern,
 };
 use common_utils::dex::liquidity::LiquidityMetrics;
+use common_utils::dex::trade_stream::{OrderBookSummary, TradeRecord, TradeStream};
 use common_utils::dex::trading_data::{TradingData, TradingVolume};
 use common_utils::dex::{DexAdapter, DexConfig, StellarDexAdapter, TokenPair};
 use common_utils::error::CommonError;
@@ -23,16 +24,39 @@ use common_utils::migration::DataMigration;
 use common_utils::rate_limit::{RateLimiter, TrustTier};
 use common_utils::storage_monitoring::{PerformanceMonitor, StorageTracker};
 use common_utils::storage_optimization::{CompressedReportStorage, DataSeparator, DataTemperature};
+use common_utils::validator::{CIDValidator, Validator};
 use common_utils::{
     auth, cached_auth, check_authorization, permission, rate_limit, rate_limit_adaptive,
 };
 use common_utils::state_machine::{State, StateMachine, FraudDetectState};
 use common_utils::{state_guard, transition_to};
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, String, Symbol,
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, Map, Symbol,
     TryFromVal, Val, Vec,
 };
 
+mod escalation;
+mod history;
+mod model;
+mod reputation;
+mod review;
+mod rules;
+mod thresholds;
+pub use escalation::{
+    ContractCall, EscalationAction, EscalationAlert, EscalationStep, MAX_ESCALATION_STEPS,
+};
+pub use history::{IndicatorSnapshot, PatternSnapshot, MAX_SNAPSHOTS};
+pub use model::{
+    FraudModel, ModelEvaluation, ModelKind, TransactionFeatures, FEATURE_COUNT, FIXED_POINT_SCALE,
+};
+pub use reputation::{ReportOutcome, ReporterReputation};
+pub use review::{ReportReview, ReportStatus, APPEAL_WINDOW};
+pub use rules::{
+    CompareOp, FraudRule, RuleCondition, RuleContext, RuleField, RuleLogic, MAX_CONDITIONS,
+    MAX_RULES,
+};
+pub use thresholds::{ThresholdChange, ThresholdSet, MAX_THRESHOLD_HISTORY};
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
@@ -43,10 +67,28 @@ pub enum DataKey {
     MigrationState,
     DexConfig,
     DexEnabled,
-    FraudIndicators(Symbol),
+    FraudIndicators(TokenPair),
     DetectionThresholds,
-    TradingPatternHistory(Symbol),
+    TradingPatternHistory(TokenPair),
     ContractState,
+    Indexer(Address),
+    TradeStream(TokenPair),
+    ReporterReputation(Address),
+    ReportReview(Symbol, u32),
+    AgentOwner(Symbol),
+    ModelCount,
+    Model(u32),
+    ActiveModel,
+    ModelActivations,
+    Rules,
+    RuleCount,
+    EscalationPolicy(TokenPair),
+    DefaultEscalationPolicy,
+    EscalationRun(TokenPair, u32),
+    PairThresholds(TokenPair),
+    ThresholdVersion,
+    ThresholdHistory,
+    ConfirmedReports(Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -98,10 +140,9 @@ impl FraudDetectContract {
             .set(&DataKey::AclContract, &acl_contract);
         env.storage().instance().set(&DataKey::DexEnabled, &true);
 
-        let thresholds = DetectionThresholds::new();
         env.storage()
             .instance()
-            .set(&DataKey::DetectionThresholds, &thresholds);
+            .set(&DataKey::DetectionThresholds, &ThresholdSet::builtin());
 
         let dex_config = DexConfig::default();
         env.storage()
@@ -113,7 +154,44 @@ impl FraudDetectContract {
         Ok(())
     }
 
+    /// Stop report submission, analyses and admin updates. Reads keep working.
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+
+        let state = Self::get_state(&env);
+        let state_data = state.get_data().ok_or(ContractError::NotInitialized)?;
+        Self::transition(&env, State::Paused(state_data.clone()))
+            .map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    pub fn resume(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        let state = Self::get_state(&env);
+        if !state.is_paused() {
+            return Err(ContractError::InvalidState);
+        }
+
+        let state_data = state.get_data().ok_or(ContractError::NotInitialized)?;
+        Self::transition(&env, State::Active(state_data.clone()))
+            .map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    /// Permanently disable the contract. Reports and scores stay readable.
+    pub fn terminate(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        Self::transition(&env, State::Terminated).map_err(|_| ContractError::InvalidState)?;
+        Ok(())
+    }
+
+    pub fn get_contract_state(env: Env) -> State<FraudDetectState> {
+        Self::get_state(&env)
+    }
+
     pub fn initialize_dex(env: Env, admin: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
 
         StellarDexAdapter::initialize(&env).map_err(|_| ContractError::InvalidState)?;
@@ -123,151 +201,401 @@ impl FraudDetectContract {
         Ok(())
     }
 
+    /// Replace the global thresholds used by pairs without an override. Returns the new version.
     pub fn set_detection_thresholds(
         env: Env,
         admin: Address,
         thresholds: DetectionThresholds,
-    ) -> Result<(), ContractError> {
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::DetectionThresholds, &thresholds);
-        Ok(())
+        thresholds
+            .validate()
+            .map_err(|_| ContractError::InvalidConfiguration)?;
+
+        let version = Self::record_threshold_change(&env, admin, None, Some(thresholds.clone()));
+        env.storage().instance().set(
+            &DataKey::DetectionThresholds,
+            &ThresholdSet {
+                version,
+                thresholds,
+            },
+        );
+        Ok(version)
     }
 
     pub fn get_detection_thresholds(env: Env) -> DetectionThresholds {
-        env.storage()
-            .instance()
-            .get(&DataKey::DetectionThresholds)
-            .unwrap_or_else(|| DetectionThresholds::new())
+        Self::global_thresholds(&env).thresholds
     }
 
-    pub fn analyze_trading_for_fraud(
+    /// Override the global thresholds for one pair. Returns the new version.
+    pub fn set_pair_thresholds(
         env: Env,
+        admin: Address,
         pair: TokenPair,
-    ) -> Result<FraudAnalysisResult, ContractError> {
-        let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "analyze_fraud"));
-
-        let dex_enabled: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::DexEnabled)
-            .unwrap_or(false);
-
-        if !dex_enabled {
-            return Ok(FraudAnalysisResult {
-                indicators: Vec::new(&env),
-                overall_risk: OverallRisk {
-                    risk_level: RiskLevel::Low,
-                    total_score_impact: 0,
-                    critical_count: 0,
-                    high_count: 0,
-                    medium_count: 0,
-                    low_count: 0,
-                },
-                trading_data: None,
-            });
-        }
+        thresholds: DetectionThresholds,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        thresholds
+            .validate()
+            .map_err(|_| ContractError::InvalidConfiguration)?;
 
-        let trading_data = Self::fetch_trading_data(&env, &pair)?;
+        let version = Self::record_threshold_change(
+            &env,
+            admin,
+            Some(pair.clone()),
+            Some(thresholds.clone()),
+        );
+        env.storage().persistent().set(
+            &DataKey::PairThresholds(pair),
+            &ThresholdSet {
+                version,
+                thresholds,
+            },
+        );
+        Ok(version)
+    }
 
-        let thresholds = Self::get_detection_thresholds(env.clone());
-        let detector = PatternDetector::with_thresholds(&env, thresholds);
+    /// Drop a pair's override so it falls back to the global thresholds
+    pub fn remove_pair_thresholds(
+        env: Env,
+        admin: Address,
+        pair: TokenPair,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
 
-        let indicators = detector.analyze(&trading_data);
-        let overall_risk = detector.overall_risk(&indicators);
+        let key = DataKey::PairThresholds(pair.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::NotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(Self::record_threshold_change(&env, admin, Some(pair), None))
+    }
 
-        let patterns = detector.detect_patterns(&trading_data);
+    /// Thresholds an analysis of `pair` runs with: its override, else the global set
+    pub fn get_pair_thresholds(env: Env, pair: TokenPair) -> ThresholdSet {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PairThresholds(pair))
+            .unwrap_or_else(|| Self::global_thresholds(&env))
+    }
 
-        env.storage().instance().set(
-            &DataKey::FraudIndicators(pair.symbol_a),
-            &indicators.clone(),
-        );
+    /// Recent threshold changes, oldest first
+    pub fn get_threshold_history(env: Env) -> Vec<ThresholdChange> {
         env.storage()
-            .instance()
-            .set(&DataKey::TradingPatternHistory(pair.symbol_a), &patterns);
+            .persistent()
+            .get(&DataKey::ThresholdHistory)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
-        StorageTracker::record_operation(
-            &env,
-            &Symbol::new(&env, "fraud_analysis"),
-            &pair.symbol_a,
-            100,
-            true,
-        );
+    /// Analyze `pair` and record the result in its history. A critical
+    /// result runs the `Alert` steps of the pair's escalation policy.
+    pub fn analyze_trading_for_fraud(
+        env: Env,
+        pair: TokenPair,
+    ) -> Result<FraudAnalysisResult, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::analyze_and_record(&env, pair).map(|(result, _)| result)
+    }
 
-        let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "analyze_fraud"));
+    /// Analyze `pair` and, when it is critical, run the remaining steps of
+    /// its escalation policy against the traders the trade stream
+    /// implicates. Requires the `("fraud", "escalate")` permission.
+    pub fn escalate(
+        env: Env,
+        caller: Address,
+        pair: TokenPair,
+    ) -> Result<FraudAnalysisResult, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_permission(&env, &caller, symbol_short!("escalate"))?;
 
-        Ok(FraudAnalysisResult {
-            indicators,
-            overall_risk,
-            trading_data: Some(trading_data),
-        })
+        let (result, alert) = Self::analyze_and_record(&env, pair)?;
+        if let Some(alert) = alert {
+            Self::run_escalations(&env, &alert, false);
+        }
+        Ok(result)
     }
 
+    /// Score a transaction with the active fraud model. When `pair` is given,
+    /// a fresh DEX analysis supplies the `pair_risk` feature; the analysis is
+    /// not recorded, so scoring stays a read and works while paused.
     pub fn analyze_transaction(
         env: Env,
-        transaction_data: String,
+        features: TransactionFeatures,
         pair: Option<TokenPair>,
-    ) -> bool {
+    ) -> Result<ModelEvaluation, ContractError> {
         let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "analyze_txn"));
 
+        let model = Self::get_active_model(env.clone()).ok_or(ContractError::NotFound)?;
+
+        let mut features = features;
         if let Some(token_pair) = pair {
-            if let Ok(result) = Self::analyze_trading_for_fraud(env.clone(), token_pair) {
-                if result.overall_risk.requires_review() {
-                    return true;
-                }
+            if let Ok(analysis) = Self::evaluate_pair(&env, &token_pair) {
+                features.pair_risk =
+                    Self::risk_level_score(analysis.result.overall_risk.risk_level) as i64;
             }
         }
 
-        let has_suspicious_pattern = transaction_data.len() > 1000;
+        let evaluation = model.evaluate(&features);
 
         let _duration = PerformanceMonitor::end_timer(&env, &Symbol::new(&env, "analyze_txn"));
 
-        has_suspicious_pattern
+        Ok(evaluation)
     }
 
+    /// Risk of a fresh analysis of `pair`; nothing is added to its history
     pub fn get_risk_score(env: Env, pair: TokenPair) -> u32 {
-        if let Ok(result) = Self::analyze_trading_for_fraud(env.clone(), pair) {
-            match result.overall_risk.risk_level {
-                RiskLevel::Low => 25,
-                RiskLevel::Medium => 50,
-                RiskLevel::High => 75,
-                RiskLevel::Critical => 100,
-            }
+        if let Ok(analysis) = Self::evaluate_pair(&env, &pair) {
+            Self::risk_level_score(analysis.result.overall_risk.risk_level)
         } else {
             0
         }
     }
 
+    /// Indicators from the pair's most recent analysis
     pub fn get_indicators(env: Env, pair: TokenPair) -> Vec<FraudIndicator> {
-        env.storage()
-            .instance()
-            .get(&DataKey::FraudIndicators(pair.symbol_a))
+        Self::indicator_series(&env, &pair)
+            .last()
+            .map(|snapshot| snapshot.indicators)
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Patterns from the pair's most recent analysis
     pub fn get_trading_patterns(env: Env, pair: TokenPair) -> Vec<TradingPattern> {
-        env.storage()
-            .instance()
-            .get(&DataKey::TradingPatternHistory(pair.symbol_a))
+        Self::pattern_series(&env, &pair)
+            .last()
+            .map(|snapshot| snapshot.patterns)
             .unwrap_or_else(|| Vec::new(&env))
     }
 
-    pub fn update_model(env: Env, admin: Address, model_data: Bytes) -> Result<(), ContractError> {
+    /// Retained analyses of `pair` run between `from` and `to`, oldest first
+    pub fn get_indicator_history(
+        env: Env,
+        pair: TokenPair,
+        from: u64,
+        to: u64,
+    ) -> Vec<IndicatorSnapshot> {
+        history::window(&env, &Self::indicator_series(&env, &pair), from, to)
+    }
+
+    pub fn get_pattern_history(
+        env: Env,
+        pair: TokenPair,
+        from: u64,
+        to: u64,
+    ) -> Vec<PatternSnapshot> {
+        history::window(&env, &Self::pattern_series(&env, &pair), from, to)
+    }
+
+    pub fn add_indexer(env: Env, admin: Address, indexer: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Indexer(indexer.clone()), &true);
+        env.events().publish((symbol_short!("idx_add"),), indexer);
+        Ok(())
+    }
+
+    pub fn remove_indexer(env: Env, admin: Address, indexer: Address) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::Indexer(indexer.clone()));
+        env.events().publish((symbol_short!("idx_rem"),), indexer);
+        Ok(())
+    }
+
+    /// Append individual trades to the pair's stream. Only registered indexers
+    /// may call this, and trades must not be older than the newest one stored.
+    pub fn record_trades(
+        env: Env,
+        indexer: Address,
+        pair: TokenPair,
+        trades: Vec<TradeRecord>,
+    ) -> Result<u64, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        indexer.require_auth();
+        if !env.storage().instance().has(&DataKey::Indexer(indexer.clone())) {
+            return Err(ContractError::Unauthorized);
+        }
+        if trades.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut stream = Self::get_trade_stream(env.clone(), pair.clone());
+        for trade in trades.iter() {
+            if !trade.is_valid() || !stream.append(trade) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TradeStream(pair.clone()), &stream);
+
+        StorageTracker::record_operation(
+            &env,
+            &Symbol::new(&env, "store"),
+            &Symbol::new(&env, "trade_stream"),
+            trades.len() * 96,
+            true,
+        );
+
+        env.events().publish(
+            (symbol_short!("trades"), pair.symbol_a, pair.symbol_b),
+            (trades.len(), stream.total_appended),
+        );
+
+        Ok(stream.total_appended)
+    }
+
+    /// Replace the pair's order book summary, which supplies the layering and
+    /// liquidity figures the trades cannot. Only registered indexers may call
+    /// this, and the summary must not be older than the one stored.
+    pub fn record_order_book(
+        env: Env,
+        indexer: Address,
+        pair: TokenPair,
+        book: OrderBookSummary,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        indexer.require_auth();
+        if !env.storage().instance().has(&DataKey::Indexer(indexer.clone())) {
+            return Err(ContractError::Unauthorized);
+        }
+        if !book.is_valid() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut stream = Self::get_trade_stream(env.clone(), pair.clone());
+        if let Some(previous) = &stream.book {
+            if book.observed_at_ms < previous.observed_at_ms {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        stream.book = Some(book.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::TradeStream(pair.clone()), &stream);
 
         env.events().publish(
-            (symbol_short!("mdl_upd"),),
-            (env.ledger().timestamp(), model_data),
+            (symbol_short!("book"), pair.symbol_a, pair.symbol_b),
+            (book.max_layered_levels, book.pool_liquidity, book.flash_liquidity),
         );
         Ok(())
     }
 
+    pub fn get_trade_stream(env: Env, pair: TokenPair) -> TradeStream {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TradeStream(pair.clone()))
+            .unwrap_or_else(|| TradeStream::new(&env, pair))
+    }
+
+    /// Store a new model version. It only takes effect once activated.
+    pub fn register_model(
+        env: Env,
+        admin: Address,
+        kind: ModelKind,
+        weights: Vec<i64>,
+        bias: i64,
+        flag_threshold: u32,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ModelCount)
+            .unwrap_or(0u32)
+            + 1;
+        let model = FraudModel {
+            version,
+            kind,
+            weights,
+            bias,
+            flag_threshold,
+            registered_at: env.ledger().timestamp(),
+        };
+        model.validate()?;
+
+        env.storage().persistent().set(&DataKey::Model(version), &model);
+        env.storage().instance().set(&DataKey::ModelCount, &version);
+
+        env.events()
+            .publish((symbol_short!("mdl_reg"),), (version, kind, env.ledger().timestamp()));
+        Ok(version)
+    }
+
+    pub fn activate_model(env: Env, admin: Address, version: u32) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        if !env.storage().persistent().has(&DataKey::Model(version)) {
+            return Err(ContractError::NotFound);
+        }
+        let previous = Self::get_active_model_version(env.clone());
+        if previous == Some(version) {
+            return Ok(());
+        }
+
+        let mut activations = Self::model_activations(&env);
+        if activations.len() >= model::MAX_ACTIVATION_HISTORY {
+            activations.pop_front();
+        }
+        activations.push_back(version);
+        env.storage()
+            .instance()
+            .set(&DataKey::ModelActivations, &activations);
+        env.storage().instance().set(&DataKey::ActiveModel, &version);
+
+        env.events()
+            .publish((symbol_short!("mdl_act"),), (previous, version));
+        Ok(())
+    }
+
+    /// Reactivate the model that was active before the current one. Returns its version.
+    pub fn rollback_model(env: Env, admin: Address) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let mut activations = Self::model_activations(&env);
+        if activations.len() < 2 {
+            return Err(ContractError::InvalidState);
+        }
+        let current = activations.pop_back().unwrap();
+        let restored = activations.last().unwrap();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ModelActivations, &activations);
+        env.storage().instance().set(&DataKey::ActiveModel, &restored);
+
+        env.events()
+            .publish((symbol_short!("mdl_rbk"),), (current, restored));
+        Ok(restored)
+    }
+
+    pub fn get_model(env: Env, version: u32) -> Option<FraudModel> {
+        env.storage().persistent().get(&DataKey::Model(version))
+    }
+
+    pub fn get_active_model_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::ActiveModel)
+    }
+
+    pub fn get_active_model(env: Env) -> Option<FraudModel> {
+        let version = Self::get_active_model_version(env.clone())?;
+        Self::get_model(env, version)
+    }
+
     pub fn add_reporter(
         env: Env,
         caller: Address,
         reporter: Address,
     ) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         caller.require_auth();
 
         let acl: Address = env
@@ -290,6 +618,7 @@ impl FraudDetectContract {
     }
 
     pub fn remove_reporter(env: Env, admin: Address, reporter: Address) -> Result<(), CommonError> {
+        state_guard!(Self, &env, active, CommonError::NotAuthorized);
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -307,6 +636,7 @@ impl FraudDetectContract {
         user: Address,
         tier: TrustTier,
     ) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -321,6 +651,7 @@ impl FraudDetectContract {
     }
 
     pub fn set_network_load(env: Env, admin: Address, load: u32) -> Result<(), AuthorizationError> {
+        state_guard!(Self, &env, active, AuthorizationError::NotAuthorized);
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -340,7 +671,8 @@ impl FraudDetectContract {
         agent_id: Symbol,
         score: u32,
         trading_evidence: Option<TradingEvidence>,
-    ) -> Result<(), ContractError> {
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         rate_limit_adaptive!(env, reporter, "submit_rpt",
             max: 10, window: 3600,
             strategy: SlidingWindow, scope: PerUser);
@@ -363,31 +695,44 @@ impl FraudDetectContract {
             return Err(ContractError::Unauthorized);
         }
 
-        let mut reports: Vec<FraudReport> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Reports(agent_id.clone()))
-            .unwrap_or(Vec::new(&env));
+        let reports = Self::get_reports(env.clone(), agent_id.clone());
+        let now = env.ledger().timestamp();
 
-        let mut adjusted_score = score;
+        let mut context = RuleContext::new(&env);
+        for existing_report in reports.iter() {
+            context.report_times.push_back(existing_report.timestamp);
+        }
+        context.report_times.push_back(now);
+        context.confirmed_reports = Self::confirmed_report_count(&env, &agent_id);
 
         if let Some(evidence) = trading_evidence {
-            if let Ok(result) = Self::analyze_trading_for_fraud(env.clone(), evidence.pair.clone())
-            {
-                let risk_adjustment = match result.overall_risk.risk_level {
-                    RiskLevel::Critical => 20,
-                    RiskLevel::High => 10,
-                    RiskLevel::Medium => 5,
-                    RiskLevel::Low => 0,
-                };
-                adjusted_score = (score + risk_adjustment).min(100);
+            if let Ok(analysis) = Self::evaluate_pair(&env, &evidence.pair) {
+                context.indicators = analysis.result.indicators;
+                context.overall_risk = Some(analysis.result.overall_risk);
             }
+            context.evidence = Some(evidence);
         }
 
+        // The most severe matching rule raises the report's risk like the
+        // analysis risk does, so rules on report fields affect the score too
+        let (fired_rules, rule_severity) =
+            rules::evaluate(&env, &Self::get_rules(env.clone()), &context);
+        let mut risk_level = context.overall_risk.as_ref().map(|risk| risk.risk_level);
+        if let Some(severity) = rule_severity {
+            risk_level = Some(rules::escalate_level(
+                risk_level.unwrap_or(RiskLevel::Low),
+                severity,
+            ));
+        }
+        let adjusted_score = match risk_level {
+            Some(level) => (score + Self::report_risk_adjustment(level)).min(100),
+            None => score,
+        };
+
         let report = FraudReport {
             score: adjusted_score,
             reporter: reporter.clone(),
-            timestamp: env.ledger().timestamp(),
+            timestamp: now,
         };
 
         let mut updated_reports = Vec::new(&env);
@@ -399,19 +744,335 @@ impl FraudDetectContract {
         CompressedReportStorage::store_reports(&env, &agent_id, &updated_reports)
             .map_err(|_| ContractError::StorageFull)?;
 
-        CompressedReportStorage::update_latest_score(&env, &agent_id, adjusted_score)
-            .map_err(|_| ContractError::StorageFull)?;
+        let report_index = updated_reports.len() - 1;
+
+        let mut review = ReportReview::new(&env);
+        review.fired_rules = fired_rules.clone();
+        env.storage().persistent().set(
+            &DataKey::ReportReview(agent_id.clone(), report_index),
+            &review,
+        );
 
         StorageTracker::record_operation(&env, &symbol_short!("store"), &agent_id, 44, true);
 
         env.events().publish(
             (symbol_short!("fraud_rpt"), agent_id),
-            (reporter, adjusted_score, env.ledger().timestamp()),
+            (reporter, report_index, adjusted_score, fired_rules, now),
         );
 
+        Ok(report_index)
+    }
+
+    /// Register a rule evaluated on every analysis. Returns the rule id.
+    pub fn add_rule(
+        env: Env,
+        admin: Address,
+        name: Symbol,
+        logic: RuleLogic,
+        conditions: Vec<RuleCondition>,
+        severity: Severity,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let mut rules = Self::get_rules(env.clone());
+        if rules.len() >= rules::MAX_RULES {
+            return Err(ContractError::StorageFull);
+        }
+
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RuleCount)
+            .unwrap_or(0u32)
+            + 1;
+        let rule = FraudRule {
+            id,
+            name,
+            logic,
+            conditions,
+            severity,
+            enabled: true,
+            created_at: env.ledger().timestamp(),
+        };
+        rule.validate()?;
+
+        rules.push_back(rule);
+        env.storage().instance().set(&DataKey::Rules, &rules);
+        env.storage().instance().set(&DataKey::RuleCount, &id);
+
+        env.events().publish((symbol_short!("rule_add"),), (id, severity));
+        Ok(id)
+    }
+
+    pub fn set_rule_enabled(
+        env: Env,
+        admin: Address,
+        rule_id: u32,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let mut rules = Self::get_rules(env.clone());
+        let position = rules
+            .iter()
+            .position(|rule| rule.id == rule_id)
+            .ok_or(ContractError::NotFound)? as u32;
+        let mut rule = rules.get(position).unwrap();
+        rule.enabled = enabled;
+        rules.set(position, rule);
+        env.storage().instance().set(&DataKey::Rules, &rules);
+
+        env.events().publish((symbol_short!("rule_set"),), (rule_id, enabled));
         Ok(())
     }
 
+    pub fn remove_rule(env: Env, admin: Address, rule_id: u32) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        let mut rules = Self::get_rules(env.clone());
+        let position = rules
+            .iter()
+            .position(|rule| rule.id == rule_id)
+            .ok_or(ContractError::NotFound)? as u32;
+        rules.remove(position);
+        env.storage().instance().set(&DataKey::Rules, &rules);
+
+        env.events().publish((symbol_short!("rule_rem"),), rule_id);
+        Ok(())
+    }
+
+    pub fn get_rules(env: Env) -> Vec<FraudRule> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Rules)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Steps run when an analysis of `pair` is critical. `None` sets the default
+    /// used by pairs without their own policy; an empty list removes the policy.
+    pub fn set_escalation_policy(
+        env: Env,
+        admin: Address,
+        pair: Option<TokenPair>,
+        steps: Vec<EscalationStep>,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+
+        if steps.len() > MAX_ESCALATION_STEPS {
+            return Err(ContractError::InvalidInput);
+        }
+        for step in steps.iter() {
+            step.validate()?;
+        }
+
+        let key = match pair.clone() {
+            Some(pair) => DataKey::EscalationPolicy(pair),
+            None => DataKey::DefaultEscalationPolicy,
+        };
+        if steps.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &steps);
+        }
+
+        env.events().publish((symbol_short!("esc_set"),), (pair, steps.len()));
+        Ok(())
+    }
+
+    /// Policy applied to `pair`, falling back to the default policy
+    pub fn get_escalation_policy(env: Env, pair: TokenPair) -> Vec<EscalationStep> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscalationPolicy(pair))
+            .or_else(|| env.storage().persistent().get(&DataKey::DefaultEscalationPolicy))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// When step `step` of the pair's policy last ran
+    pub fn get_last_escalation(env: Env, pair: TokenPair, step: u32) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscalationRun(pair, step))
+    }
+
+    /// Register the address that speaks for `agent_id` when appealing reports
+    pub fn set_agent_owner(
+        env: Env,
+        admin: Address,
+        agent_id: Symbol,
+        owner: Address,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AgentOwner(agent_id.clone()), &owner);
+        env.events().publish((symbol_short!("agt_own"), agent_id), owner);
+        Ok(())
+    }
+
+    pub fn get_agent_owner(env: Env, agent_id: Symbol) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::AgentOwner(agent_id))
+    }
+
+    pub fn start_review(
+        env: Env,
+        reviewer: Address,
+        agent_id: Symbol,
+        report_index: u32,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_permission(&env, &reviewer, symbol_short!("review"))?;
+
+        let mut review = Self::load_review(&env, &agent_id, report_index)?;
+        if review.status != ReportStatus::Submitted {
+            return Err(ContractError::InvalidState);
+        }
+        review.status = ReportStatus::UnderReview;
+        review.reviewer = Some(reviewer.clone());
+
+        Self::save_review(&env, &agent_id, report_index, &mut review);
+        env.events().publish(
+            (symbol_short!("rpt_rev"), agent_id),
+            (report_index, reviewer),
+        );
+        Ok(())
+    }
+
+    /// Confirm or dismiss a report under review. Returns the agent's new score.
+    pub fn decide_report(
+        env: Env,
+        reviewer: Address,
+        agent_id: Symbol,
+        report_index: u32,
+        outcome: ReportOutcome,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_permission(&env, &reviewer, symbol_short!("review"))?;
+
+        let mut review = Self::load_review(&env, &agent_id, report_index)?;
+        if review.status != ReportStatus::UnderReview {
+            return Err(ContractError::InvalidState);
+        }
+        review.status = match outcome {
+            ReportOutcome::Confirmed => ReportStatus::Confirmed,
+            ReportOutcome::Overturned => ReportStatus::Dismissed,
+        };
+        review.decision = Some(outcome);
+        review.reviewer = Some(reviewer.clone());
+        Self::save_review(&env, &agent_id, report_index, &mut review);
+
+        let score = Self::refresh_score(&env, &agent_id)?;
+        env.events().publish(
+            (symbol_short!("rpt_dec"), agent_id),
+            (report_index, outcome, reviewer, score),
+        );
+        Ok(score)
+    }
+
+    /// Contest a confirmed report. Only the agent's registered owner may appeal.
+    pub fn appeal_report(
+        env: Env,
+        agent_id: Symbol,
+        report_index: u32,
+        evidence_cid: Bytes,
+    ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        let owner = Self::get_agent_owner(env.clone(), agent_id.clone())
+            .ok_or(ContractError::Unauthorized)?;
+        owner.require_auth();
+
+        CIDValidator::new()
+            .validate(&env, &evidence_cid)
+            .map_err(|_| ContractError::InvalidInput)?;
+
+        let mut review = Self::load_review(&env, &agent_id, report_index)?;
+        if !review.in_appeal_window(env.ledger().timestamp()) {
+            return Err(ContractError::InvalidState);
+        }
+        review.status = ReportStatus::Appealed;
+        review.appeal_cid = Some(evidence_cid.clone());
+
+        Self::save_review(&env, &agent_id, report_index, &mut review);
+        env.events().publish(
+            (symbol_short!("rpt_apl"), agent_id),
+            (report_index, owner, evidence_cid),
+        );
+        Ok(())
+    }
+
+    /// Close a decided or appealed report and settle the reporter's reputation.
+    /// `reverse` overturns the decision and is only allowed on appealed reports.
+    /// A confirmed report waits out its appeal window first. Returns the
+    /// agent's new score.
+    pub fn finalize_report(
+        env: Env,
+        executor: Address,
+        agent_id: Symbol,
+        report_index: u32,
+        reverse: bool,
+    ) -> Result<u32, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
+        Self::require_permission(&env, &executor, symbol_short!("execute"))?;
+
+        let mut review = Self::load_review(&env, &agent_id, report_index)?;
+        let decision = match review.status {
+            ReportStatus::Confirmed | ReportStatus::Dismissed | ReportStatus::Appealed => {
+                review.decision.ok_or(ContractError::InvalidState)?
+            }
+            _ => return Err(ContractError::InvalidState),
+        };
+        if reverse && review.status != ReportStatus::Appealed {
+            return Err(ContractError::InvalidInput);
+        }
+        if review.in_appeal_window(env.ledger().timestamp()) {
+            return Err(ContractError::InvalidState);
+        }
+
+        let outcome = match (decision, reverse) {
+            (ReportOutcome::Confirmed, true) => ReportOutcome::Overturned,
+            (ReportOutcome::Overturned, true) => ReportOutcome::Confirmed,
+            (decision, false) => decision,
+        };
+        let (weight, score) =
+            Self::close_review(&env, &agent_id, report_index, &mut review, outcome, &executor)?;
+        env.events().publish(
+            (symbol_short!("rpt_final"), agent_id),
+            (report_index, outcome, executor, weight, score),
+        );
+        Ok(score)
+    }
+
+    /// Final decision on a report; `None` until its review is final
+    pub fn get_report_outcome(
+        env: Env,
+        agent_id: Symbol,
+        report_index: u32,
+    ) -> Option<ReportOutcome> {
+        Self::get_report_review(env, agent_id, report_index)
+            .filter(|review| review.status == ReportStatus::Final)
+            .and_then(|review| review.decision)
+    }
+
+    pub fn get_report_review(
+        env: Env,
+        agent_id: Symbol,
+        report_index: u32,
+    ) -> Option<ReportReview> {
+        Self::load_review(&env, &agent_id, report_index).ok()
+    }
+
+    pub fn get_reporter_reputation(env: Env, reporter: Address) -> ReporterReputation {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReporterReputation(reporter.clone()))
+            .unwrap_or_else(|| ReporterReputation::new(&env, reporter))
+    }
+
     pub fn get_reports(env: Env, agent_id: Symbol) -> Vec<FraudReport> {
         let _timer = PerformanceMonitor::start_timer(&env, &symbol_short!("get_reports"));
 
@@ -441,6 +1102,7 @@ impl FraudDetectContract {
         env: Env,
         pairs: Vec<TokenPair>,
     ) -> Result<Vec<FraudAnalysisResult>, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         let _timer = PerformanceMonitor::start_timer(&env, &Symbol::new(&env, "batch_analyze"));
 
         let mut results = Vec::new(&env);
@@ -461,6 +1123,7 @@ impl FraudDetectContract {
         admin: Address,
         pair: TokenPair,
     ) -> Result<(), ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         Self::require_admin(&env, &admin)?;
 
         let mut cache = DexDataCache::new(&env);
@@ -486,6 +1149,7 @@ impl FraudDetectContract {
     }
 
     pub fn migrate_to_compressed(env: Env, admin: Address) -> Result<u64, ContractError> {
+        state_guard!(Self, &env, active, ContractError::InvalidState);
         let auth = Self::get_auth(&env);
         check_authorization!(auth, &env, &admin, permission!(Admin));
 
@@ -496,6 +1160,402 @@ impl FraudDetectContract {
         Ok(0)
     }
 
+    /// Reputation-weighted median of each reporter's latest confirmed report.
+    /// Other agents pick up a reporter's new weight the next time one of
+    /// their own reports is decided or finalized.
+    fn consensus_score(env: &Env, agent_id: &Symbol, reports: &Vec<FraudReport>) -> u32 {
+        let mut latest: Map<Address, u32> = Map::new(env);
+        for (index, report) in reports.iter().enumerate() {
+            let review: Option<ReportReview> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReportReview(agent_id.clone(), index as u32));
+            if review.map_or(false, |review| review.counts_toward_score()) {
+                latest.set(report.reporter, report.score);
+            }
+        }
+
+        let mut entries = Vec::new(env);
+        for (reporter, score) in latest.iter() {
+            let weight = Self::get_reporter_reputation(env.clone(), reporter).weight;
+            entries.push_back((score, weight));
+        }
+        reputation::weighted_median(env, &entries)
+    }
+
+    fn refresh_score(env: &Env, agent_id: &Symbol) -> Result<u32, ContractError> {
+        let reports = Self::get_reports(env.clone(), agent_id.clone());
+        let score = Self::consensus_score(env, agent_id, &reports);
+        CompressedReportStorage::update_latest_score(env, agent_id, score)
+            .map_err(|_| ContractError::StorageFull)?;
+        Ok(score)
+    }
+
+    /// Stored review, or a `Submitted` one for a report filed before reviews
+    /// existed; saving it backfills the legacy report
+    fn load_review(
+        env: &Env,
+        agent_id: &Symbol,
+        report_index: u32,
+    ) -> Result<ReportReview, ContractError> {
+        if let Some(review) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReportReview(agent_id.clone(), report_index))
+        {
+            return Ok(review);
+        }
+        let report = Self::get_reports(env.clone(), agent_id.clone())
+            .get(report_index)
+            .ok_or(ContractError::NotFound)?;
+        Ok(ReportReview::submitted_at(env, report.timestamp))
+    }
+
+    /// Make `outcome` the review's final decision and settle the reporter's
+    /// reputation. Returns the reporter's new weight and the agent's new score.
+    fn close_review(
+        env: &Env,
+        agent_id: &Symbol,
+        report_index: u32,
+        review: &mut ReportReview,
+        outcome: ReportOutcome,
+        executor: &Address,
+    ) -> Result<(u32, u32), ContractError> {
+        let reports = Self::get_reports(env.clone(), agent_id.clone());
+        let report = reports.get(report_index).ok_or(ContractError::NotFound)?;
+
+        review.status = ReportStatus::Final;
+        review.decision = Some(outcome);
+        review.executor = Some(executor.clone());
+        Self::save_review(env, agent_id, report_index, review);
+
+        let mut reputation = Self::get_reporter_reputation(env.clone(), report.reporter.clone());
+        match outcome {
+            ReportOutcome::Confirmed => reputation.record_confirmed(env),
+            ReportOutcome::Overturned => reputation.record_overturned(env),
+        }
+        env.storage().persistent().set(
+            &DataKey::ReporterReputation(report.reporter.clone()),
+            &reputation,
+        );
+
+        let score = Self::refresh_score(env, agent_id)?;
+        Ok((reputation.weight, score))
+    }
+
+    /// Store `review`, keeping the agent's confirmed report count in step
+    fn save_review(env: &Env, agent_id: &Symbol, report_index: u32, review: &mut ReportReview) {
+        let key = DataKey::ReportReview(agent_id.clone(), report_index);
+        let previous: Option<ReportReview> = env.storage().persistent().get(&key);
+        let counted = previous.map_or(false, |previous| previous.counts_toward_score());
+        if counted != review.counts_toward_score() {
+            let confirmed = Self::confirmed_report_count(env, agent_id);
+            let confirmed = if counted {
+                confirmed.saturating_sub(1)
+            } else {
+                confirmed + 1
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::ConfirmedReports(agent_id.clone()), &confirmed);
+        }
+
+        review.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, review);
+    }
+
+    /// Reports against the agent whose current decision is `Confirmed`
+    fn confirmed_report_count(env: &Env, agent_id: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ConfirmedReports(agent_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Points added to a report's score for the risk found behind it
+    fn report_risk_adjustment(level: RiskLevel) -> u32 {
+        match level {
+            RiskLevel::Critical => 20,
+            RiskLevel::High => 10,
+            RiskLevel::Medium => 5,
+            RiskLevel::Low => 0,
+        }
+    }
+
+    /// ACL check for `("fraud", action)`
+    fn require_permission(env: &Env, user: &Address, action: Symbol) -> Result<(), ContractError> {
+        user.require_auth();
+        let acl: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AclContract)
+            .ok_or(ContractError::NotInitialized)?;
+
+        if !common_utils::check_permission(
+            env.clone(),
+            acl,
+            user.clone(),
+            symbol_short!("fraud"),
+            action,
+        ) {
+            return Err(ContractError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn global_thresholds(env: &Env) -> ThresholdSet {
+        env.storage()
+            .instance()
+            .get(&DataKey::DetectionThresholds)
+            .unwrap_or_else(ThresholdSet::builtin)
+    }
+
+    /// Assign the next threshold version and append the change to the history
+    fn record_threshold_change(
+        env: &Env,
+        admin: Address,
+        pair: Option<TokenPair>,
+        thresholds: Option<DetectionThresholds>,
+    ) -> u32 {
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ThresholdVersion)
+            .unwrap_or(0u32)
+            + 1;
+        env.storage().instance().set(&DataKey::ThresholdVersion, &version);
+
+        let mut changes = Self::get_threshold_history(env.clone());
+        if changes.len() >= thresholds::MAX_THRESHOLD_HISTORY {
+            changes.pop_front();
+        }
+        changes.push_back(ThresholdChange {
+            version,
+            pair: pair.clone(),
+            thresholds,
+            changed_by: admin,
+            changed_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ThresholdHistory, &changes);
+
+        env.events().publish((symbol_short!("thr_set"),), (version, pair));
+        version
+    }
+
+    /// Run the analysis of `pair`, store it in the pair's history and return
+    /// the alert for a critical result after running its `Alert` steps
+    fn analyze_and_record(
+        env: &Env,
+        pair: TokenPair,
+    ) -> Result<(FraudAnalysisResult, Option<EscalationAlert>), ContractError> {
+        let _timer = PerformanceMonitor::start_timer(env, &Symbol::new(env, "analyze_fraud"));
+
+        let analysis = Self::evaluate_pair(env, &pair)?;
+        let result = analysis.result;
+        if result.trading_data.is_none() {
+            return Ok((result, None));
+        }
+
+        let analyzed_at = env.ledger().timestamp();
+        let mut indicator_history = Self::indicator_series(env, &pair);
+        history::push_bounded(
+            &mut indicator_history,
+            IndicatorSnapshot {
+                analyzed_at,
+                overall_risk: result.overall_risk.clone(),
+                indicators: result.indicators.clone(),
+                fired_rules: result.fired_rules.clone(),
+                threshold_version: result.threshold_version,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::FraudIndicators(pair.clone()), &indicator_history);
+
+        let mut pattern_history = Self::pattern_series(env, &pair);
+        history::push_bounded(
+            &mut pattern_history,
+            PatternSnapshot {
+                analyzed_at,
+                patterns: analysis.patterns,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::TradingPatternHistory(pair.clone()), &pattern_history);
+
+        StorageTracker::record_operation(
+            env,
+            &Symbol::new(env, "fraud_analysis"),
+            &pair.symbol_a,
+            100,
+            true,
+        );
+
+        let _duration = PerformanceMonitor::end_timer(env, &Symbol::new(env, "analyze_fraud"));
+
+        if !result.fired_rules.is_empty() {
+            env.events().publish(
+                (symbol_short!("rule_hit"), pair.symbol_a.clone(), pair.symbol_b.clone()),
+                (result.fired_rules.clone(), result.overall_risk.risk_level),
+            );
+        }
+
+        let alert = if result.overall_risk.risk_level == RiskLevel::Critical {
+            let alert = EscalationAlert {
+                pair,
+                risk_level: result.overall_risk.risk_level,
+                critical_count: result.overall_risk.critical_count,
+                total_score_impact: result.overall_risk.total_score_impact,
+                fired_rules: result.fired_rules.clone(),
+                flagged_accounts: analysis.flagged_accounts,
+                analyzed_at,
+            };
+            Self::run_escalations(env, &alert, true);
+            Some(alert)
+        } else {
+            None
+        };
+
+        Ok((result, alert))
+    }
+
+    /// Analysis of `pair` that stores no result, for read paths
+    fn evaluate_pair(env: &Env, pair: &TokenPair) -> Result<PairAnalysis, ContractError> {
+        let threshold_set = Self::get_pair_thresholds(env.clone(), pair.clone());
+
+        let dex_enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::DexEnabled)
+            .unwrap_or(false);
+
+        if !dex_enabled {
+            return Ok(PairAnalysis {
+                result: FraudAnalysisResult {
+                    indicators: Vec::new(env),
+                    overall_risk: OverallRisk {
+                        risk_level: RiskLevel::Low,
+                        total_score_impact: 0,
+                        critical_count: 0,
+                        high_count: 0,
+                        medium_count: 0,
+                        low_count: 0,
+                    },
+                    trading_data: None,
+                    fired_rules: Vec::new(env),
+                    threshold_version: threshold_set.version,
+                },
+                patterns: Vec::new(env),
+                flagged_accounts: Vec::new(env),
+            });
+        }
+
+        let trading_data = Self::fetch_trading_data(env, pair)?;
+
+        let frontrun_window_ms = threshold_set.thresholds.frontrun_time_window_ms;
+        let detector = PatternDetector::with_thresholds(env, threshold_set.thresholds);
+        let stream = Self::get_trade_stream(env.clone(), pair.clone());
+        let order_flow = stream.order_flow(env, frontrun_window_ms);
+
+        let indicators = if stream.has_order_flow() {
+            detector.analyze_with_order_flow(&trading_data, &order_flow)
+        } else {
+            detector.analyze(&trading_data)
+        };
+        let mut overall_risk = detector.overall_risk(&indicators);
+
+        let mut context = RuleContext::new(env);
+        context.indicators = indicators.clone();
+        context.overall_risk = Some(overall_risk.clone());
+        let (fired_rules, rule_severity) =
+            rules::evaluate(env, &Self::get_rules(env.clone()), &context);
+        if let Some(severity) = rule_severity {
+            rules::escalate(&mut overall_risk, severity);
+        }
+
+        let patterns = detector.detect_patterns(&trading_data, &stream, &order_flow);
+        let flagged_accounts = if overall_risk.risk_level == RiskLevel::Critical {
+            stream.flagged_traders(env, frontrun_window_ms)
+        } else {
+            Vec::new(env)
+        };
+
+        Ok(PairAnalysis {
+            result: FraudAnalysisResult {
+                indicators,
+                overall_risk,
+                trading_data: Some(trading_data),
+                fired_rules,
+                threshold_version: threshold_set.version,
+            },
+            patterns,
+            flagged_accounts,
+        })
+    }
+
+    /// Run the steps of the pair's escalation policy that are not cooling
+    /// down: its `Alert` steps when `alerts` is set, the others otherwise
+    fn run_escalations(env: &Env, alert: &EscalationAlert, alerts: bool) {
+        let now = env.ledger().timestamp();
+        let steps = Self::get_escalation_policy(env.clone(), alert.pair.clone());
+        for (index, step) in steps.iter().enumerate() {
+            if (step.action == EscalationAction::Alert) != alerts {
+                continue;
+            }
+            let key = DataKey::EscalationRun(alert.pair.clone(), index as u32);
+            let last_run: Option<u64> = env.storage().persistent().get(&key);
+            if !step.applies_to(alert) || step.is_cooling_down(last_run, now) {
+                continue;
+            }
+
+            if escalation::execute(env, &step.action, alert) {
+                env.storage().persistent().set(&key, &now);
+            } else {
+                env.events().publish(
+                    (
+                        symbol_short!("esc_fail"),
+                        alert.pair.symbol_a.clone(),
+                        alert.pair.symbol_b.clone(),
+                    ),
+                    index as u32,
+                );
+            }
+        }
+    }
+
+    fn model_activations(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ModelActivations)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn risk_level_score(level: RiskLevel) -> u32 {
+        match level {
+            RiskLevel::Low => 25,
+            RiskLevel::Medium => 50,
+            RiskLevel::High => 75,
+            RiskLevel::Critical => 100,
+        }
+    }
+
+    fn indicator_series(env: &Env, pair: &TokenPair) -> Vec<IndicatorSnapshot> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FraudIndicators(pair.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn pattern_series(env: &Env, pair: &TokenPair) -> Vec<PatternSnapshot> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TradingPatternHistory(pair.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
     fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
         let stored_admin: Address = env
             .storage()
@@ -548,12 +1608,24 @@ impl FraudDetectContract {
     }
 }
 
+/// One analysis run before anything is stored
+struct PairAnalysis {
+    result: FraudAnalysisResult,
+    patterns: Vec<TradingPattern>,
+    /// Traders the stream implicates; only gathered for a critical result
+    flagged_accounts: Vec<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct FraudAnalysisResult {
     pub indicators: Vec<FraudIndicator>,
     pub overall_risk: OverallRisk,
     pub trading_data: Option<TradingData>,
+    /// Ids of the rules that matched this analysis
+    pub fired_rules: Vec<u32>,
+    /// Version of the thresholds the analysis ran with
+    pub threshold_version: u32,
 }
 
 #[contracttype]
@@ -574,6 +1646,13 @@ impl DataMigration for FraudDetectContract {
     }
 
     fn import_state(env: Env, data: Vec<Val>) -> Result<(), CommonError> {
+        state_guard!(Self, &env, active, CommonError::NotAuthorized);
+        let current_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(CommonError::NotInitialized)?;
+        current_admin.require_auth();
         if data.len() < 1 {
             return Err(CommonError::InvalidFormat);
         }
@@ -0,0 +1,124 @@
+//! # Fraud Model Registry
+//!
+//! Versioned parameter sets for the transaction fraud model. A model is a
+//! linear or logistic function over [`TransactionFeatures`] with fixed-point
+//! weights and bias (`FIXED_POINT_SCALE` = 1.0). Registering a model does not
+//! activate it; the admin activates a version explicitly and can roll back to
+//! the previously active one.
+//!
+//! - `Linear` - the weighted sum is the fraud probability.
+//! - `Logistic` - the weighted sum is passed through a sigmoid.
+
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, Vec};
+
+/// Fixed-point representation of 1.0 for weights and bias
+pub const FIXED_POINT_SCALE: i64 = 10_000;
+/// Number of features in [`TransactionFeatures`]; every model has one weight per feature
+pub const FEATURE_COUNT: u32 = 6;
+/// Activations remembered for rollback
+pub const MAX_ACTIVATION_HISTORY: u32 = 20;
+
+/// sigmoid(x) for x = 0, 1, ..., 8 scaled by `FIXED_POINT_SCALE`
+const SIGMOID_TABLE: [i64; 9] = [5000, 7311, 8808, 9526, 9820, 9933, 9975, 9991, 9997];
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelKind {
+    Linear,
+    Logistic,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FraudModel {
+    pub version: u32,
+    pub kind: ModelKind,
+    /// One fixed-point weight per feature, in `TransactionFeatures` field order
+    pub weights: Vec<i64>,
+    pub bias: i64,
+    /// Score (0-100) from which a transaction is flagged
+    pub flag_threshold: u32,
+    pub registered_at: u64,
+}
+
+/// Inputs to the transaction model
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransactionFeatures {
+    pub amount_usd: i64,
+    pub txs_last_hour: i64,
+    pub account_age_days: i64,
+    pub distinct_counterparties: i64,
+    pub failed_txs_last_day: i64,
+    /// 0-100 risk of the traded pair; filled in from the DEX analysis when a pair is given
+    pub pair_risk: i64,
+}
+
+impl TransactionFeatures {
+    pub fn values(&self) -> [i64; FEATURE_COUNT as usize] {
+        [
+            self.amount_usd,
+            self.txs_last_hour,
+            self.account_age_days,
+            self.distinct_counterparties,
+            self.failed_txs_last_day,
+            self.pair_risk,
+        ]
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelEvaluation {
+    /// Fraud score 0-100
+    pub score: u32,
+    pub model_version: u32,
+    pub flagged: bool,
+}
+
+impl FraudModel {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.weights.len() != FEATURE_COUNT || self.flag_threshold > 100 {
+            return Err(ContractError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+
+    pub fn evaluate(&self, features: &TransactionFeatures) -> ModelEvaluation {
+        let mut z = self.bias as i128;
+        for (weight, value) in self.weights.iter().zip(features.values().iter()) {
+            z += weight as i128 * *value as i128;
+        }
+        let z = z.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+
+        let probability = match self.kind {
+            ModelKind::Linear => z,
+            ModelKind::Logistic => sigmoid(z),
+        };
+        let score = (probability.clamp(0, FIXED_POINT_SCALE) * 100 / FIXED_POINT_SCALE) as u32;
+
+        ModelEvaluation {
+            score,
+            model_version: self.version,
+            flagged: score >= self.flag_threshold,
+        }
+    }
+}
+
+/// Fixed-point sigmoid, interpolated linearly between integer points
+fn sigmoid(x: i64) -> i64 {
+    let magnitude = x.unsigned_abs().min(8 * FIXED_POINT_SCALE as u64) as i64;
+    let index = (magnitude / FIXED_POINT_SCALE) as usize;
+    let fraction = magnitude % FIXED_POINT_SCALE;
+
+    let lower = SIGMOID_TABLE[index];
+    let upper = SIGMOID_TABLE[(index + 1).min(SIGMOID_TABLE.len() - 1)];
+    let positive = lower + (upper - lower) * fraction / FIXED_POINT_SCALE;
+
+    if x >= 0 {
+        positive
+    } else {
+        FIXED_POINT_SCALE - positive
+    }
+}
@@ -0,0 +1,84 @@
+//! # Reporter Reputation
+//!
+//! Every reporter carries a weight that grows when one of their reports is
+//! confirmed and shrinks when one is overturned. An agent's fraud score is
+//! the weighted median of the latest confirmed report from each reporter, so a
+//! single low-reputation reporter cannot move it on their own.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Weight of a reporter without any resolved reports
+pub const INITIAL_WEIGHT: u32 = 100;
+pub const MIN_WEIGHT: u32 = 10;
+pub const MAX_WEIGHT: u32 = 1000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReporterReputation {
+    pub reporter: Address,
+    pub weight: u32,
+    pub confirmed: u32,
+    pub overturned: u32,
+    pub updated_at: u64,
+}
+
+impl ReporterReputation {
+    pub fn new(env: &Env, reporter: Address) -> Self {
+        Self {
+            reporter,
+            weight: INITIAL_WEIGHT,
+            confirmed: 0,
+            overturned: 0,
+            updated_at: env.ledger().timestamp(),
+        }
+    }
+
+    /// A confirmed report adds a quarter of the current weight
+    pub fn record_confirmed(&mut self, env: &Env) {
+        self.confirmed += 1;
+        self.weight = (self.weight + self.weight / 4).clamp(MIN_WEIGHT, MAX_WEIGHT);
+        self.updated_at = env.ledger().timestamp();
+    }
+
+    /// An overturned report halves the weight, so trust is lost faster than it is earned
+    pub fn record_overturned(&mut self, env: &Env) {
+        self.overturned += 1;
+        self.weight = (self.weight / 2).clamp(MIN_WEIGHT, MAX_WEIGHT);
+        self.updated_at = env.ledger().timestamp();
+    }
+}
+
+/// Outcome of reviewing a single report
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportOutcome {
+    Confirmed,
+    Overturned,
+}
+
+/// Weighted median of `(score, weight)` pairs: the lowest score at which
+/// the cumulative weight reaches half of the total. Returns 0 when empty.
+pub fn weighted_median(env: &Env, entries: &Vec<(u32, u32)>) -> u32 {
+    let mut sorted: Vec<(u32, u32)> = Vec::new(env);
+    let mut total_weight: u64 = 0;
+    for (score, weight) in entries.iter() {
+        if weight == 0 {
+            continue;
+        }
+        let position = sorted
+            .iter()
+            .position(|(other, _)| score < other)
+            .unwrap_or(sorted.len() as usize);
+        sorted.insert(position as u32, (score, weight));
+        total_weight += weight as u64;
+    }
+
+    let mut cumulative: u64 = 0;
+    for (score, weight) in sorted.iter() {
+        cumulative += weight as u64;
+        if cumulative * 2 >= total_weight {
+            return score;
+        }
+    }
+    0
+}
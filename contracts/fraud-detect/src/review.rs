@@ -0,0 +1,82 @@
+//! # Fraud Report Review
+//!
+//! Every report moves through
+//! `Submitted -> UnderReview -> Confirmed | Dismissed -> [Appealed] -> Final`.
+//!
+//! - Reviews are opened and decided by callers holding the ACL
+//!   `("fraud", "review")` permission (granted to `Role::Reviewer`).
+//! - The flagged agent's owner may appeal a confirmed report with an evidence
+//!   CID.
+//! - Callers holding `("fraud", "execute")` (granted to `Role::Executor`)
+//!   finalize a decision, and only they can reverse it, and only on appeal.
+//!
+//! A confirmed report can only be finalized once [`APPEAL_WINDOW`] has passed
+//! since the decision, and the owner must appeal within that window.
+//!
+//! Only reports whose current decision is `Confirmed` count toward the
+//! agent's score. Reporter reputation changes once a report is final.
+//!
+//! Reports filed before reviews existed have no stored review; they are
+//! treated as `Submitted` and get a review the first time one is acted on.
+
+use soroban_sdk::{contracttype, Address, Bytes, Env, Vec};
+
+use crate::reputation::ReportOutcome;
+
+/// Seconds the agent's owner has to appeal a confirmed report
+pub const APPEAL_WINDOW: u64 = 7 * 24 * 3600;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportStatus {
+    Submitted,
+    UnderReview,
+    Confirmed,
+    Dismissed,
+    Appealed,
+    Final,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReportReview {
+    pub status: ReportStatus,
+    /// Latest decision; set by the reviewer and possibly reversed on appeal
+    pub decision: Option<ReportOutcome>,
+    pub reviewer: Option<Address>,
+    pub appeal_cid: Option<Bytes>,
+    pub executor: Option<Address>,
+    /// Rules that matched when the report was submitted
+    pub fired_rules: Vec<u32>,
+    pub submitted_at: u64,
+    pub updated_at: u64,
+}
+
+impl ReportReview {
+    pub fn new(env: &Env) -> Self {
+        Self::submitted_at(env, env.ledger().timestamp())
+    }
+
+    /// Fresh review for a report filed at `submitted_at`
+    pub fn submitted_at(env: &Env, submitted_at: u64) -> Self {
+        Self {
+            status: ReportStatus::Submitted,
+            decision: None,
+            reviewer: None,
+            appeal_cid: None,
+            executor: None,
+            fired_rules: Vec::new(env),
+            submitted_at,
+            updated_at: submitted_at,
+        }
+    }
+
+    /// A confirmed report is still open to appeal at `now`
+    pub fn in_appeal_window(&self, now: u64) -> bool {
+        self.status == ReportStatus::Confirmed && now < self.updated_at.saturating_add(APPEAL_WINDOW)
+    }
+
+    pub fn counts_toward_score(&self) -> bool {
+        self.decision == Some(ReportOutcome::Confirmed)
+    }
+}
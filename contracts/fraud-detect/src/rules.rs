@@ -0,0 +1,223 @@
+//! # Fraud Rule Engine
+//!
+//! Compliance rules registered by the admin as data rather than code. A rule
+//! compares fields of the analysis context against constants and combines
+//! the comparisons with AND (`All`) or OR (`Any`), e.g. "more than 5 reports
+//! in 24h and risk at least High":
+//!
+//! ```text
+//! All [ ReportsWithin(86400) > 5, RiskLevel >= 2 ]
+//! ```
+//!
+//! Fields without data in the current context (no agent, no trading
+//! evidence) evaluate to 0. A fired rule raises the analysis risk level to at
+//! least the rule's severity, and its id is recorded with the analysis.
+
+use common_utils::dex::fraud_indicators::{
+    FraudIndicator, IndicatorType, OverallRisk, RiskLevel, Severity,
+};
+use common_utils::error::ContractError;
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+use crate::TradingEvidence;
+
+pub const MAX_RULES: u32 = 50;
+pub const MAX_CONDITIONS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleField {
+    /// Reports filed against the agent in the last N seconds
+    ReportsWithin(u64),
+    ConfirmedReports,
+    /// Indicators of one type in the analysis
+    IndicatorCount(IndicatorType),
+    IndicatorTotal,
+    /// `OverallRisk::risk_level` as 0 (Low) to 3 (Critical)
+    RiskLevel,
+    CriticalCount,
+    HighCount,
+    MediumCount,
+    LowCount,
+    ScoreImpact,
+    /// 1 when the report's trading evidence flags a volume anomaly
+    VolumeAnomaly,
+    /// 1 when the report's trading evidence flags a price anomaly
+    PriceAnomaly,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Eq,
+    Ne,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleCondition {
+    pub field: RuleField,
+    pub op: CompareOp,
+    pub value: i64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleLogic {
+    All,
+    Any,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FraudRule {
+    pub id: u32,
+    pub name: Symbol,
+    pub logic: RuleLogic,
+    pub conditions: Vec<RuleCondition>,
+    pub severity: Severity,
+    pub enabled: bool,
+    pub created_at: u64,
+}
+
+impl FraudRule {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.conditions.is_empty() || self.conditions.len() > MAX_CONDITIONS {
+            return Err(ContractError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, context: &RuleContext) -> bool {
+        let mut results = self.conditions.iter().map(|c| c.holds(context));
+        match self.logic {
+            RuleLogic::All => results.all(|held| held),
+            RuleLogic::Any => results.any(|held| held),
+        }
+    }
+}
+
+impl RuleCondition {
+    pub fn holds(&self, context: &RuleContext) -> bool {
+        let actual = context.value(&self.field);
+        match self.op {
+            CompareOp::Gt => actual > self.value,
+            CompareOp::Gte => actual >= self.value,
+            CompareOp::Lt => actual < self.value,
+            CompareOp::Lte => actual <= self.value,
+            CompareOp::Eq => actual == self.value,
+            CompareOp::Ne => actual != self.value,
+        }
+    }
+}
+
+/// Everything a rule can look at for one analysis call
+pub struct RuleContext {
+    pub now: u64,
+    /// Timestamps of reports filed against the agent
+    pub report_times: Vec<u64>,
+    pub confirmed_reports: u32,
+    pub indicators: Vec<FraudIndicator>,
+    pub overall_risk: Option<OverallRisk>,
+    pub evidence: Option<TradingEvidence>,
+}
+
+impl RuleContext {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            now: env.ledger().timestamp(),
+            report_times: Vec::new(env),
+            confirmed_reports: 0,
+            indicators: Vec::new(env),
+            overall_risk: None,
+            evidence: None,
+        }
+    }
+
+    pub fn value(&self, field: &RuleField) -> i64 {
+        let risk = self.overall_risk.as_ref();
+        match field {
+            RuleField::ReportsWithin(seconds) => {
+                let since = self.now.saturating_sub(*seconds);
+                self.report_times.iter().filter(|t| *t >= since).count() as i64
+            }
+            RuleField::ConfirmedReports => self.confirmed_reports as i64,
+            RuleField::IndicatorCount(indicator_type) => self
+                .indicators
+                .iter()
+                .filter(|i| i.indicator_type == *indicator_type)
+                .count() as i64,
+            RuleField::IndicatorTotal => self.indicators.len() as i64,
+            RuleField::RiskLevel => risk.map_or(0, |r| risk_rank(r.risk_level)),
+            RuleField::CriticalCount => risk.map_or(0, |r| r.critical_count as i64),
+            RuleField::HighCount => risk.map_or(0, |r| r.high_count as i64),
+            RuleField::MediumCount => risk.map_or(0, |r| r.medium_count as i64),
+            RuleField::LowCount => risk.map_or(0, |r| r.low_count as i64),
+            RuleField::ScoreImpact => risk.map_or(0, |r| r.total_score_impact as i64),
+            RuleField::VolumeAnomaly => self.evidence.as_ref().map_or(0, |e| e.volume_anomaly as i64),
+            RuleField::PriceAnomaly => self.evidence.as_ref().map_or(0, |e| e.price_anomaly as i64),
+        }
+    }
+}
+
+/// Ids of the enabled rules that match, and the highest severity among them
+pub fn evaluate(
+    env: &Env,
+    rules: &Vec<FraudRule>,
+    context: &RuleContext,
+) -> (Vec<u32>, Option<Severity>) {
+    let mut fired = Vec::new(env);
+    let mut highest: Option<Severity> = None;
+    for rule in rules.iter() {
+        if !rule.enabled || !rule.matches(context) {
+            continue;
+        }
+        fired.push_back(rule.id);
+        if highest.map_or(true, |s| severity_rank(rule.severity) > severity_rank(s)) {
+            highest = Some(rule.severity);
+        }
+    }
+    (fired, highest)
+}
+
+/// Raise `risk` to at least the level matching `severity`
+pub fn escalate(risk: &mut OverallRisk, severity: Severity) {
+    risk.risk_level = escalate_level(risk.risk_level, severity);
+}
+
+/// The higher of `level` and the level matching `severity`
+pub fn escalate_level(level: RiskLevel, severity: Severity) -> RiskLevel {
+    let floor = match severity {
+        Severity::Low => RiskLevel::Low,
+        Severity::Medium => RiskLevel::Medium,
+        Severity::High => RiskLevel::High,
+        Severity::Critical => RiskLevel::Critical,
+    };
+    if risk_rank(floor) > risk_rank(level) {
+        floor
+    } else {
+        level
+    }
+}
+
+fn risk_rank(level: RiskLevel) -> i64 {
+    match level {
+        RiskLevel::Low => 0,
+        RiskLevel::Medium => 1,
+        RiskLevel::High => 2,
+        RiskLevel::Critical => 3,
+    }
+}
+
+fn severity_rank(severity: Severity) -> u32 {
+    match severity {
+        Severity::Low => 0,
+        Severity::Medium => 1,
+        Severity::High => 2,
+        Severity::Critical => 3,
+    }
+}
@@ -104,3 +104,826 @@ fn test_double_initialization() {
     client.initialize(&admin);
     client.initialize(&admin);
 }
+
+#[test]
+fn test_pause_resume_terminate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, FraudDetectContract);
+    let client = FraudDetectContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let acl = Address::generate(&env);
+    let agent_id = symbol_short!("agent_1");
+    client.initialize(&admin, &acl);
+
+    client.pause(&admin);
+    assert!(client.get_contract_state().is_paused());
+    assert!(client
+        .try_set_detection_thresholds(&admin, &DetectionThresholds::new())
+        .is_err());
+    assert_eq!(
+        client.try_import_state(&vec![&env, admin.to_val()]),
+        Err(Ok(CommonError::NotAuthorized))
+    );
+
+    // Reads keep working while paused
+    assert_eq!(client.get_latest_score(&agent_id), 0);
+    assert_eq!(client.get_reports(&agent_id).len(), 0);
+
+    // Only the admin controls the lifecycle
+    assert!(client.try_resume(&Address::generate(&env)).is_err());
+    client.resume(&admin);
+    client.set_detection_thresholds(&admin, &DetectionThresholds::new());
+
+    client.terminate(&admin);
+    assert!(client.get_contract_state().is_terminated());
+    assert!(client.try_resume(&admin).is_err());
+    assert!(client
+        .try_set_detection_thresholds(&admin, &DetectionThresholds::new())
+        .is_err());
+}
+
+#[test]
+fn test_indexer_records_trades() {
+    use common_utils::dex::trade_stream::TradeRecord;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, FraudDetectContract);
+    let client = FraudDetectContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let indexer = Address::generate(&env);
+    let trader = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env));
+
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    let trade = |is_buy: bool, timestamp_ms: u64| TradeRecord {
+        trader: trader.clone(),
+        counterparty: None,
+        is_buy,
+        size: 100,
+        price: 1_000,
+        timestamp_ms,
+        cancels: 0,
+    };
+    let trades = vec![&env, trade(true, 1_000), trade(false, 2_000)];
+
+    // Unregistered indexers are rejected
+    assert!(client.try_record_trades(&indexer, &pair, &trades).is_err());
+
+    client.add_indexer(&admin, &indexer);
+    assert_eq!(client.record_trades(&indexer, &pair, &trades), 2);
+
+    let stream = client.get_trade_stream(&pair);
+    assert_eq!(stream.len(), 2);
+    assert_eq!(stream.traded_volume(), 200);
+
+    // Trades older than the newest stored one are refused
+    let stale = vec![&env, trade(true, 1_500)];
+    assert!(client.try_record_trades(&indexer, &pair, &stale).is_err());
+    assert_eq!(client.get_trade_stream(&pair).len(), 2);
+}
+
+/// Grants `("fraud", action)` per user, standing in for the ACL contract's roles
+#[soroban_sdk::contract]
+struct MockAcl;
+
+#[soroban_sdk::contractimpl]
+impl MockAcl {
+    pub fn grant(env: Env, user: Address, action: Symbol) {
+        env.storage().instance().set(&(user, action), &true);
+    }
+
+    pub fn has_permission(env: Env, user: Address, _resource: Symbol, action: Symbol) -> bool {
+        env.storage().instance().has(&(user, action))
+    }
+}
+
+fn setup_reviews(env: &Env) -> (FraudDetectContractClient, MockAclClient, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, FraudDetectContract);
+    let client = FraudDetectContractClient::new(env, &contract_id);
+    let acl_id = env.register_contract(None, MockAcl);
+    let acl = MockAclClient::new(env, &acl_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin, &acl_id);
+    (client, acl, admin)
+}
+
+#[test]
+fn test_reputation_weighted_consensus() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, acl, admin) = setup_reviews(&env);
+
+    let reviewer = Address::generate(&env);
+    let executor = Address::generate(&env);
+    acl.grant(&reviewer, &symbol_short!("review"));
+    acl.grant(&executor, &symbol_short!("execute"));
+
+    let honest_a = Address::generate(&env);
+    let honest_b = Address::generate(&env);
+    let outlier = Address::generate(&env);
+    for reporter in [&honest_a, &honest_b, &outlier] {
+        acl.grant(reporter, &symbol_short!("report"));
+    }
+    let agent_id = symbol_short!("agent_1");
+
+    client.submit_report(&honest_a, &agent_id, &20, &None);
+    client.submit_report(&honest_b, &agent_id, &30, &None);
+    client.submit_report(&outlier, &agent_id, &95, &None);
+
+    for index in 0..3u32 {
+        client.start_review(&reviewer, &agent_id, &index);
+        client.decide_report(&reviewer, &agent_id, &index, &ReportOutcome::Confirmed);
+    }
+    // The latest report no longer overwrites the score
+    assert_eq!(client.get_latest_score(&agent_id), 30);
+
+    // Reversing the outlier's report on appeal drops it and halves their weight
+    client.set_agent_owner(&admin, &agent_id, &Address::generate(&env));
+    let cid = Bytes::from_slice(&env, b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    client.appeal_report(&agent_id, &2, &cid);
+    assert_eq!(client.finalize_report(&executor, &agent_id, &2, &true), 20);
+    assert_eq!(client.get_reporter_reputation(&outlier).weight, 50);
+
+    // Finalizing a confirmation waits out the appeal window, then raises the
+    // reporter's weight
+    assert_eq!(
+        client.try_finalize_report(&executor, &agent_id, &1, &false),
+        Err(Ok(ContractError::InvalidState))
+    );
+    env.ledger().with_mut(|li| li.timestamp += APPEAL_WINDOW);
+    client.finalize_report(&executor, &agent_id, &1, &false);
+    let reputation = client.get_reporter_reputation(&honest_b);
+    assert_eq!(reputation.weight, 125);
+    assert_eq!(reputation.confirmed, 1);
+    assert_eq!(client.get_latest_score(&agent_id), 30);
+}
+
+#[test]
+fn test_report_review_lifecycle() {
+    let env = Env::default();
+    let (client, acl, admin) = setup_reviews(&env);
+
+    let reporter = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    let executor = Address::generate(&env);
+    let owner = Address::generate(&env);
+    acl.grant(&reporter, &symbol_short!("report"));
+    acl.grant(&reviewer, &symbol_short!("review"));
+    acl.grant(&executor, &symbol_short!("execute"));
+
+    let agent_id = symbol_short!("agent_1");
+    client.set_agent_owner(&admin, &agent_id, &owner);
+
+    let index = client.submit_report(&reporter, &agent_id, &80, &None);
+    let review = client.get_report_review(&agent_id, &index).unwrap();
+    assert_eq!(review.status, ReportStatus::Submitted);
+
+    // Unreviewed reports do not count toward the score
+    assert_eq!(client.get_latest_score(&agent_id), 0);
+
+    // Only reviewers move a report through review, and only in order
+    assert!(client.try_start_review(&executor, &agent_id, &index).is_err());
+    assert!(client
+        .try_decide_report(&reviewer, &agent_id, &index, &ReportOutcome::Confirmed)
+        .is_err());
+    client.start_review(&reviewer, &agent_id, &index);
+    assert_eq!(
+        client.decide_report(&reviewer, &agent_id, &index, &ReportOutcome::Confirmed),
+        80
+    );
+
+    // The agent appeals with an evidence CID; reviewers cannot finalize
+    let cid = Bytes::from_slice(&env, b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    assert!(client
+        .try_appeal_report(&agent_id, &index, &Bytes::from_slice(&env, b"bad"))
+        .is_err());
+    client.appeal_report(&agent_id, &index, &cid);
+    let review = client.get_report_review(&agent_id, &index).unwrap();
+    assert_eq!(review.status, ReportStatus::Appealed);
+    assert_eq!(review.appeal_cid, Some(cid.clone()));
+    assert_eq!(client.get_latest_score(&agent_id), 80);
+
+    assert!(client
+        .try_finalize_report(&reviewer, &agent_id, &index, &true)
+        .is_err());
+    assert_eq!(client.get_report_outcome(&agent_id, &index), None);
+    assert_eq!(client.finalize_report(&executor, &agent_id, &index, &true), 0);
+    assert_eq!(
+        client.get_report_outcome(&agent_id, &index),
+        Some(ReportOutcome::Overturned)
+    );
+
+    let review = client.get_report_review(&agent_id, &index).unwrap();
+    assert_eq!(review.status, ReportStatus::Final);
+    assert_eq!(review.decision, Some(ReportOutcome::Overturned));
+    assert_eq!(client.get_reporter_reputation(&reporter).overturned, 1);
+
+    // Final reports cannot be reopened, and unappealed ones cannot be reversed
+    assert!(client
+        .try_finalize_report(&executor, &agent_id, &index, &false)
+        .is_err());
+    let second = client.submit_report(&reporter, &agent_id, &60, &None);
+    client.start_review(&reviewer, &agent_id, &second);
+    client.decide_report(&reviewer, &agent_id, &second, &ReportOutcome::Overturned);
+    assert!(client
+        .try_finalize_report(&executor, &agent_id, &second, &true)
+        .is_err());
+    assert!(client.try_appeal_report(&agent_id, &second, &cid).is_err());
+}
+
+#[test]
+fn test_appeal_window_and_legacy_reports() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, acl, admin) = setup_reviews(&env);
+    let reviewer = Address::generate(&env);
+    let executor = Address::generate(&env);
+    acl.grant(&reviewer, &symbol_short!("review"));
+    acl.grant(&executor, &symbol_short!("execute"));
+
+    // A report stored before reviews existed has no review of its own
+    let agent_id = symbol_short!("agent_1");
+    let reporter = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    env.as_contract(&client.address, || {
+        let report = FraudReport {
+            score: 70,
+            reporter: reporter.clone(),
+            timestamp: 500,
+        };
+        CompressedReportStorage::store_reports(&env, &agent_id, &vec![&env, report]).unwrap();
+    });
+    let review = client.get_report_review(&agent_id, &0).unwrap();
+    assert_eq!(review.status, ReportStatus::Submitted);
+    assert_eq!(review.submitted_at, 500);
+    assert_eq!(client.get_report_review(&agent_id, &1), None);
+
+    client.start_review(&reviewer, &agent_id, &0);
+    client.decide_report(&reviewer, &agent_id, &0, &ReportOutcome::Confirmed);
+    assert_eq!(
+        client.get_report_review(&agent_id, &0).unwrap().status,
+        ReportStatus::Confirmed
+    );
+
+    // Appeals are only accepted inside the window, and finalization only after it
+    client.set_agent_owner(&admin, &agent_id, &Address::generate(&env));
+    let cid = Bytes::from_slice(&env, b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    assert!(client
+        .try_finalize_report(&executor, &agent_id, &0, &false)
+        .is_err());
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + APPEAL_WINDOW);
+    assert_eq!(
+        client.try_appeal_report(&agent_id, &0, &cid),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(client.finalize_report(&executor, &agent_id, &0, &false), 70);
+}
+
+/// Prime the DEX cache so analyses of `pair` do not need a live pool
+fn prime_trading_data(env: &Env, contract_id: &Address, pair: &TokenPair) {
+    use common_utils::dex::trading_data::PriceData;
+
+    env.as_contract(contract_id, || {
+        let volume = TradingVolume::new(env, pair.clone(), 1000, 1000, 1000, 1, 3600);
+        let price = PriceData::new(env, pair.clone(), 1_000_000, 7, "test");
+        let data = TradingData::new(env, pair.clone(), volume, price);
+        DexDataCache::new(env).set_trading_data(pair, data, "test");
+    });
+}
+
+#[test]
+fn test_indicator_history_per_pair() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, _acl, _admin) = setup_reviews(&env);
+
+    let base = Address::generate(&env);
+    let xlm_usdc = TokenPair::new(&env, base.clone(), Address::generate(&env), "XLM", "USDC");
+    let xlm_btc = TokenPair::new(&env, base, Address::generate(&env), "XLM", "BTC");
+
+    prime_trading_data(&env, &client.address, &xlm_usdc);
+    prime_trading_data(&env, &client.address, &xlm_btc);
+
+    for timestamp in [1_000u64, 2_000, 3_000] {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+        client.analyze_trading_for_fraud(&xlm_usdc);
+    }
+    client.analyze_trading_for_fraud(&xlm_btc);
+
+    // Repeat analyses in one ledger replace its snapshot, and risk reads
+    // record nothing
+    client.analyze_trading_for_fraud(&xlm_usdc);
+    client.get_risk_score(&xlm_usdc);
+
+    // Pairs sharing a base token no longer overwrite each other
+    assert_eq!(client.get_indicator_history(&xlm_usdc, &0, &u64::MAX).len(), 3);
+    assert_eq!(client.get_indicator_history(&xlm_btc, &0, &u64::MAX).len(), 1);
+
+    let window = client.get_indicator_history(&xlm_usdc, &1_500, &3_000);
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.get(0).unwrap().analyzed_at, 2_000);
+    assert_eq!(client.get_pattern_history(&xlm_usdc, &2_500, &2_900).len(), 0);
+}
+
+#[test]
+fn test_order_book_summary_feeds_detectors() {
+    use common_utils::dex::trade_stream::OrderBookSummary;
+
+    let env = Env::default();
+    let (client, _acl, admin) = setup_reviews(&env);
+    let indexer = Address::generate(&env);
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    prime_trading_data(&env, &client.address, &pair);
+
+    let book = OrderBookSummary {
+        max_layered_levels: 6,
+        pool_liquidity: 10_000,
+        flash_liquidity: 2_500,
+        observed_at_ms: 5_000,
+    };
+    assert_eq!(
+        client.try_record_order_book(&indexer, &pair, &book),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    client.add_indexer(&admin, &indexer);
+
+    let mut invalid = book.clone();
+    invalid.flash_liquidity = -1;
+    assert!(client.try_record_order_book(&indexer, &pair, &invalid).is_err());
+
+    client.record_order_book(&indexer, &pair, &book);
+    let result = client.analyze_trading_for_fraud(&pair);
+    let fired = |indicator_type: IndicatorType| {
+        result
+            .indicators
+            .iter()
+            .any(|indicator| indicator.indicator_type == indicator_type)
+    };
+    assert!(fired(IndicatorType::Layering));
+    assert!(fired(IndicatorType::LiquidityManipulation));
+
+    // Older summaries are refused
+    let mut stale = book.clone();
+    stale.observed_at_ms = 4_000;
+    assert!(client.try_record_order_book(&indexer, &pair, &stale).is_err());
+    assert_eq!(client.get_trade_stream(&pair).book, Some(book));
+}
+
+#[test]
+fn test_model_registry_and_evaluation() {
+    let env = Env::default();
+    let (client, _acl, admin) = setup_reviews(&env);
+
+    let features = TransactionFeatures {
+        amount_usd: 5_000,
+        txs_last_hour: 3,
+        account_age_days: 30,
+        distinct_counterparties: 2,
+        failed_txs_last_day: 10,
+        pair_risk: 50,
+    };
+
+    // Nothing to evaluate until a model is active
+    assert!(client.try_analyze_transaction(&features, &None).is_err());
+
+    // 0.1 + 0.01 per failed transaction + 0.005 per point of pair risk
+    let linear = client.register_model(
+        &admin,
+        &ModelKind::Linear,
+        &vec![&env, 0, 0, 0, 0, 100, 50],
+        &1_000,
+        &40,
+    );
+    let logistic = client.register_model(
+        &admin,
+        &ModelKind::Logistic,
+        &vec![&env, 0, 0, 0, 0, 0, 0],
+        &FIXED_POINT_SCALE,
+        &80,
+    );
+    assert!(client
+        .try_register_model(&admin, &ModelKind::Linear, &vec![&env, 1, 2], &0, &50)
+        .is_err());
+
+    client.activate_model(&admin, &linear);
+    let evaluation = client.analyze_transaction(&features, &None);
+    assert_eq!(evaluation.score, 45);
+    assert_eq!(evaluation.model_version, linear);
+    assert!(evaluation.flagged);
+
+    // sigmoid(1.0) ~= 0.73
+    client.activate_model(&admin, &logistic);
+    let evaluation = client.analyze_transaction(&features, &None);
+    assert_eq!(evaluation.score, 73);
+    assert_eq!(evaluation.model_version, logistic);
+    assert!(!evaluation.flagged);
+
+    assert_eq!(client.rollback_model(&admin), linear);
+    assert_eq!(client.get_active_model_version(), Some(linear));
+    assert!(client.try_rollback_model(&admin).is_err());
+    assert!(client.try_activate_model(&admin, &99).is_err());
+
+    // Pair risk is evaluated without recording an analysis, even while paused
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    prime_trading_data(&env, &client.address, &pair);
+    client.pause(&admin);
+    let mut with_risk = features.clone();
+    with_risk.pair_risk = client.get_risk_score(&pair) as i64;
+    assert_eq!(
+        client.analyze_transaction(&features, &Some(pair.clone())),
+        client.analyze_transaction(&with_risk, &None)
+    );
+    assert!(client.get_indicator_history(&pair, &0, &u64::MAX).is_empty());
+}
+
+#[test]
+fn test_rule_engine() {
+    let env = Env::default();
+    let (client, acl, admin) = setup_reviews(&env);
+
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    prime_trading_data(&env, &client.address, &pair);
+
+    // Escalates a clean analysis to High
+    let no_wash_trading = client.add_rule(
+        &admin,
+        &symbol_short!("no_wash"),
+        &RuleLogic::All,
+        &vec![
+            &env,
+            RuleCondition {
+                field: RuleField::IndicatorCount(IndicatorType::WashTrading),
+                op: CompareOp::Eq,
+                value: 0,
+            },
+        ],
+        &Severity::High,
+    );
+    let result = client.analyze_trading_for_fraud(&pair);
+    assert_eq!(result.fired_rules, vec![&env, no_wash_trading]);
+    assert_eq!(result.overall_risk.risk_level, RiskLevel::High);
+
+    client.set_rule_enabled(&admin, &no_wash_trading, &false);
+    let result = client.analyze_trading_for_fraud(&pair);
+    assert!(result.fired_rules.is_empty());
+    assert_eq!(result.overall_risk.risk_level, RiskLevel::Low);
+
+    // More than 2 reports in 24h with a volume anomaly
+    let burst = client.add_rule(
+        &admin,
+        &symbol_short!("burst"),
+        &RuleLogic::All,
+        &vec![
+            &env,
+            RuleCondition {
+                field: RuleField::ReportsWithin(86_400),
+                op: CompareOp::Gt,
+                value: 2,
+            },
+            RuleCondition {
+                field: RuleField::VolumeAnomaly,
+                op: CompareOp::Eq,
+                value: 1,
+            },
+        ],
+        &Severity::Critical,
+    );
+    assert!(client
+        .try_add_rule(
+            &admin,
+            &symbol_short!("empty"),
+            &RuleLogic::Any,
+            &Vec::new(&env),
+            &Severity::Low
+        )
+        .is_err());
+
+    let reporter = Address::generate(&env);
+    acl.grant(&reporter, &symbol_short!("report"));
+    let agent_id = symbol_short!("agent_1");
+    let evidence = TradingEvidence {
+        pair: pair.clone(),
+        volume_anomaly: true,
+        price_anomaly: false,
+    };
+
+    let first = client.submit_report(&reporter, &agent_id, &40, &Some(evidence.clone()));
+    client.submit_report(&reporter, &agent_id, &40, &None);
+    let third = client.submit_report(&reporter, &agent_id, &40, &Some(evidence));
+
+    let fired = |index: u32| client.get_report_review(&agent_id, &index).unwrap().fired_rules;
+    assert!(fired(first).is_empty());
+    assert_eq!(fired(third), vec![&env, burst]);
+
+    // The critical rule raises the third report's score like a critical analysis
+    let reports = client.get_reports(&agent_id);
+    assert_eq!(reports.get(first).unwrap().score, 40);
+    assert_eq!(reports.get(third).unwrap().score, 60);
+
+    // Confirmed reports are counted as reviews are decided
+    let reviewer = Address::generate(&env);
+    acl.grant(&reviewer, &symbol_short!("review"));
+    let repeat = client.add_rule(
+        &admin,
+        &symbol_short!("repeat"),
+        &RuleLogic::All,
+        &vec![
+            &env,
+            RuleCondition {
+                field: RuleField::ConfirmedReports,
+                op: CompareOp::Gte,
+                value: 1,
+            },
+        ],
+        &Severity::Medium,
+    );
+    client.start_review(&reviewer, &agent_id, &first);
+    client.decide_report(&reviewer, &agent_id, &first, &ReportOutcome::Confirmed);
+    let fourth = client.submit_report(&reporter, &agent_id, &40, &None);
+    assert!(fired(fourth).contains(&repeat));
+    assert_eq!(client.get_reports(&agent_id).get(fourth).unwrap().score, 45);
+
+    client.remove_rule(&admin, &burst);
+    assert_eq!(client.get_rules().len(), 1);
+}
+
+#[soroban_sdk::contract]
+struct MockCreditScore;
+
+#[soroban_sdk::contractimpl]
+impl MockCreditScore {
+    pub fn freeze_score(env: Env, account_id: Address) {
+        let freezes: u32 = env.storage().instance().get(&account_id).unwrap_or(0);
+        env.storage().instance().set(&account_id, &(freezes + 1));
+    }
+
+    pub fn freezes(env: Env, account_id: Address) -> u32 {
+        env.storage().instance().get(&account_id).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_critical_risk_escalation_with_cooldown() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    let (client, acl, admin) = setup_reviews(&env);
+    let escalator = Address::generate(&env);
+    acl.grant(&escalator, &symbol_short!("escalate"));
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    let other_pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "EURC",
+    );
+    prime_trading_data(&env, &client.address, &pair);
+    prime_trading_data(&env, &client.address, &other_pair);
+
+    client.add_rule(
+        &admin,
+        &symbol_short!("always"),
+        &RuleLogic::All,
+        &vec![
+            &env,
+            RuleCondition {
+                field: RuleField::IndicatorTotal,
+                op: CompareOp::Gte,
+                value: 0,
+            },
+        ],
+        &Severity::Critical,
+    );
+
+    // A frontrunner trades ahead of a larger buy and sells straight after it
+    let indexer = Address::generate(&env);
+    let front = Address::generate(&env);
+    let victim = Address::generate(&env);
+    let trade = |trader: &Address, is_buy: bool, size: i128, timestamp_ms: u64| TradeRecord {
+        trader: trader.clone(),
+        counterparty: None,
+        is_buy,
+        size,
+        price: 1_000,
+        timestamp_ms,
+        cancels: 0,
+    };
+    client.add_indexer(&admin, &indexer);
+    client.record_trades(
+        &indexer,
+        &pair,
+        &vec![
+            &env,
+            trade(&front, true, 10, 1_000),
+            trade(&victim, true, 100, 1_200),
+            trade(&front, false, 10, 1_500),
+        ],
+    );
+
+    let credit_id = env.register_contract(None, MockCreditScore);
+    let credit = MockCreditScoreClient::new(&env, &credit_id);
+    client.set_escalation_policy(
+        &admin,
+        &Some(pair.clone()),
+        &vec![
+            &env,
+            EscalationStep {
+                action: EscalationAction::Alert,
+                cooldown: 3_600,
+            },
+            EscalationStep {
+                action: EscalationAction::FreezeScore(credit_id.clone()),
+                cooldown: 0,
+            },
+        ],
+    );
+    assert!(client
+        .try_set_escalation_policy(
+            &admin,
+            &None,
+            &vec![
+                &env,
+                EscalationStep {
+                    action: EscalationAction::TimelockOperation(
+                        Address::generate(&env),
+                        ContractCall {
+                            contract: credit_id.clone(),
+                            function: symbol_short!("freezes"),
+                            args: vec![&env],
+                        },
+                        7,
+                    ),
+                    cooldown: 0,
+                },
+            ],
+        )
+        .is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    // A plain analysis only raises the alert; the other steps need the
+    // escalate permission
+    let result = client.analyze_trading_for_fraud(&pair);
+    assert_eq!(result.overall_risk.risk_level, RiskLevel::Critical);
+    assert_eq!(client.get_last_escalation(&pair, &0), Some(1_000));
+    assert_eq!(client.get_last_escalation(&pair, &1), None);
+    assert_eq!(credit.freezes(&front), 0);
+    assert_eq!(
+        client.try_escalate(&Address::generate(&env), &pair),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    let result = client.escalate(&escalator, &pair);
+    assert_eq!(result.overall_risk.risk_level, RiskLevel::Critical);
+    assert_eq!(client.get_last_escalation(&pair, &1), Some(1_000));
+    assert_eq!(credit.freezes(&front), 1);
+    assert_eq!(credit.freezes(&victim), 0);
+
+    // The alert is still cooling down; the freeze has no cooldown
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.escalate(&escalator, &pair);
+    assert_eq!(client.get_last_escalation(&pair, &0), Some(1_000));
+    assert_eq!(client.get_last_escalation(&pair, &1), Some(2_000));
+    assert_eq!(credit.freezes(&front), 2);
+
+    // No policy for the other pair and no default
+    client.escalate(&escalator, &other_pair);
+    assert!(client.get_escalation_policy(&other_pair).is_empty());
+    assert_eq!(client.get_last_escalation(&other_pair, &0), None);
+
+    client.set_escalation_policy(
+        &admin,
+        &None,
+        &vec![
+            &env,
+            EscalationStep {
+                action: EscalationAction::Alert,
+                cooldown: 3_600,
+            },
+        ],
+    );
+    client.escalate(&escalator, &other_pair);
+    assert_eq!(client.get_last_escalation(&other_pair, &0), Some(2_000));
+
+    // The other pair's stream implicates nobody, so a freeze step is skipped
+    client.set_escalation_policy(
+        &admin,
+        &Some(other_pair.clone()),
+        &vec![
+            &env,
+            EscalationStep {
+                action: EscalationAction::FreezeScore(credit_id.clone()),
+                cooldown: 0,
+            },
+        ],
+    );
+    client.escalate(&escalator, &other_pair);
+    assert_eq!(client.get_last_escalation(&other_pair, &0), None);
+}
+
+#[test]
+fn test_pair_threshold_overrides_and_history() {
+    let env = Env::default();
+    let (client, _acl, admin) = setup_reviews(&env);
+    let pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "USDC",
+    );
+    let other_pair = TokenPair::new(
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        "XLM",
+        "EURC",
+    );
+    prime_trading_data(&env, &client.address, &pair);
+    prime_trading_data(&env, &client.address, &other_pair);
+
+    assert_eq!(client.analyze_trading_for_fraud(&pair).threshold_version, 0);
+
+    let mut invalid = DetectionThresholds::new();
+    invalid.min_confidence_for_alert = 500;
+    assert_eq!(
+        client.try_set_detection_thresholds(&admin, &invalid),
+        Err(Ok(ContractError::InvalidConfiguration))
+    );
+    let mut invalid = DetectionThresholds::new();
+    invalid.unusual_volume_multiplier = 0;
+    assert!(client.try_set_pair_thresholds(&admin, &pair, &invalid).is_err());
+
+    let mut global = DetectionThresholds::new();
+    global.price_deviation_bps = 800;
+    assert_eq!(client.set_detection_thresholds(&admin, &global), 1);
+
+    let mut strict = DetectionThresholds::new();
+    strict.price_deviation_bps = 100;
+    assert_eq!(client.set_pair_thresholds(&admin, &pair, &strict), 2);
+
+    let overridden = client.get_pair_thresholds(&pair);
+    assert_eq!(overridden.version, 2);
+    assert_eq!(overridden.thresholds.price_deviation_bps, 100);
+    assert_eq!(client.get_pair_thresholds(&other_pair).version, 1);
+
+    assert_eq!(client.analyze_trading_for_fraud(&pair).threshold_version, 2);
+    assert_eq!(client.analyze_trading_for_fraud(&other_pair).threshold_version, 1);
+    let snapshot = client.get_indicator_history(&pair, &0, &u64::MAX).last().unwrap();
+    assert_eq!(snapshot.threshold_version, 2);
+
+    // Removing the override falls back to the global thresholds
+    assert_eq!(client.remove_pair_thresholds(&admin, &pair), 3);
+    assert_eq!(client.get_pair_thresholds(&pair).version, 1);
+    assert!(client.try_remove_pair_thresholds(&admin, &pair).is_err());
+
+    let changes = client.get_threshold_history();
+    assert_eq!(changes.len(), 3);
+    assert_eq!(changes.get(0).unwrap().pair, None);
+    assert_eq!(changes.get(1).unwrap().pair, Some(pair.clone()));
+    assert!(changes.get(2).unwrap().thresholds.is_none());
+    assert_eq!(changes.get(2).unwrap().changed_by, admin);
+}
@@ -0,0 +1,43 @@
+//! # Detection Threshold Versions
+//!
+//! The admin sets a global [`DetectionThresholds`] and may override it for
+//! individual pairs; a pair without an override uses the global set. Every
+//! change, including removing an override, gets the next version number and
+//! is appended to a bounded change history. Analyses record the version of
+//! the thresholds they ran with, so a past result can be traced back to the
+//! exact configuration that produced it. Version 0 is the built-in default.
+
+use common_utils::dex::fraud_indicators::DetectionThresholds;
+use common_utils::dex::TokenPair;
+use soroban_sdk::{contracttype, Address};
+
+/// Threshold changes retained in the history
+pub const MAX_THRESHOLD_HISTORY: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ThresholdSet {
+    pub version: u32,
+    pub thresholds: DetectionThresholds,
+}
+
+impl ThresholdSet {
+    pub fn builtin() -> Self {
+        Self {
+            version: 0,
+            thresholds: DetectionThresholds::new(),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ThresholdChange {
+    pub version: u32,
+    /// `None` for the global thresholds
+    pub pair: Option<TokenPair>,
+    /// `None` when a pair override was removed
+    pub thresholds: Option<DetectionThresholds>,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}